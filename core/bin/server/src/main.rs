@@ -146,12 +146,10 @@ async fn main() -> anyhow::Result<()> {
     // It's a `ServerCommand::Launch`, perform the usual routine.
     vlog::info!("Running the zkSync server");
 
-    run_server(&opt.components).await;
-
-    Ok(())
+    run_server(&opt.components).await
 }
 
-async fn run_server(components: &ComponentsToRun) {
+async fn run_server(components: &ComponentsToRun) -> anyhow::Result<()> {
     let connection_pool = ConnectionPool::new(None);
     let read_only_connection_pool = ConnectionPool::new_readonly_pool(None);
     let (stop_signal_sender, mut stop_signal_receiver) = mpsc::channel(256);
@@ -164,7 +162,7 @@ async fn run_server(components: &ComponentsToRun) {
             connection_pool.clone(),
             &Web3Config::from_env(),
             &TokenConfig::from_env(),
-        ));
+        )?);
     }
 
     if components.0.contains(&Component::Fetchers) {
@@ -341,6 +339,7 @@ async fn run_server(components: &ComponentsToRun) {
             vlog::warn!("Stop signal received, shutting down");
         }
     };
+    Ok(())
 }
 
 pub fn run_forced_exit(connection_pool: ConnectionPool) -> Vec<JoinHandle<()>> {
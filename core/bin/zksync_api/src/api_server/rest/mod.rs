@@ -1,5 +1,5 @@
 use actix_cors::Cors;
-use actix_web::{web, App, HttpResponse, HttpServer};
+use actix_web::{dev::Service, web, App, HttpResponse, HttpServer};
 use futures::channel::mpsc;
 use std::net::SocketAddr;
 use zksync_storage::ConnectionPool;
@@ -15,6 +15,7 @@ use super::tx_sender::TxSender;
 use crate::api_server::rest::network_status::SharedNetworkStatus;
 use crate::fee_ticker::FeeTicker;
 use tokio::task::JoinHandle;
+use tracing::Instrument;
 use zksync_config::ZkSyncConfig;
 use zksync_mempool::MempoolTransactionRequest;
 
@@ -69,7 +70,10 @@ async fn start_server(
             )
             .service(api_v01.into_scope())
             .service(forced_exit_requests_api_scope)
-            .service(api_v02_scope)
+            .service(api_v02_scope.wrap_fn(|req, srv| {
+                let span = v02::request_span(&req);
+                srv.call(req).instrument(span)
+            }))
             // Endpoint needed for js isReachable
             .route(
                 "/favicon.ico",
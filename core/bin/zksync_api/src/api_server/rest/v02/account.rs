@@ -53,7 +53,11 @@ impl ApiAccountData {
         match account_address_or_id {
             AccountAddressOrId::Id(account_id) => Ok(Some(account_id)),
             AccountAddressOrId::Address(address) => {
-                let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+                let mut storage = self
+                    .pool
+                    .access_storage()
+                    .await
+                    .map_err(Error::storage_unavailable)?;
                 let account_id = storage
                     .chain()
                     .account_schema()
@@ -71,7 +75,11 @@ impl ApiAccountData {
     ) -> Result<Address, Error> {
         match account_address_or_id {
             AccountAddressOrId::Id(account_id) => {
-                let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+                let mut storage = self
+                    .pool
+                    .access_storage()
+                    .await
+                    .map_err(Error::storage_unavailable)?;
                 let address = storage
                     .chain()
                     .account_schema()
@@ -173,7 +181,11 @@ impl ApiAccountData {
         &self,
         account_id: AccountId,
     ) -> Result<Option<Account>, Error> {
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
         let mut transaction = storage.start_transaction().await.map_err(Error::storage)?;
         let ((last_block, _), account) = transaction
             .chain()
@@ -206,7 +218,11 @@ impl ApiAccountData {
         &self,
         account_id: AccountId,
     ) -> Result<Option<Account>, Error> {
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
         let mut transaction = storage.start_transaction().await.map_err(Error::storage)?;
         let (last_block, account) = transaction
             .chain()
@@ -236,7 +252,11 @@ impl ApiAccountData {
         address: Address,
         account_id: Option<AccountId>,
     ) -> Result<AccountState, Error> {
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
         let mut transaction = storage.start_transaction().await.map_err(Error::storage)?;
 
         let depositing = get_depositing(
@@ -297,7 +317,11 @@ impl ApiAccountData {
         token_like: Option<TokenLike>,
         second_address: Option<Address>,
     ) -> Result<Paginated<Transaction, TxHashSerializeWrapper>, Error> {
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
         let token = if let Some(token_like) = token_like {
             Some(
                 self.tokens
@@ -342,7 +366,11 @@ impl ApiAccountData {
             limit: query.limit,
             direction: query.direction,
         };
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
         storage.paginate_checked(&new_query).await
     }
 }
@@ -350,14 +378,19 @@ impl ApiAccountData {
 async fn account_committed_info(
     data: web::Data<ApiAccountData>,
     account_id_or_address: web::Path<String>,
-) -> ApiResult<Option<Account>> {
+) -> ApiResult<Account> {
     let start = Instant::now();
     let address_or_id = api_try!(data.parse_account_id_or_address(&account_id_or_address));
     let account_id = api_try!(data.get_id_by_address_or_id(address_or_id).await);
-    let res = if let Some(account_id) = account_id {
-        data.account_committed_info(account_id).await.into()
-    } else {
-        ApiResult::Ok(None)
+    let res = match account_id {
+        Some(account_id) => data
+            .account_committed_info(account_id)
+            .await
+            .and_then(|account| {
+                account.ok_or_else(|| Error::from(InvalidDataError::AccountNotFound))
+            })
+            .into(),
+        None => Error::from(InvalidDataError::AccountNotFound).into(),
     };
     metrics::histogram!("api", start.elapsed(), "type" => "v02", "endpoint_name" => "account_committed_info");
     res
@@ -366,14 +399,19 @@ async fn account_committed_info(
 async fn account_finalized_info(
     data: web::Data<ApiAccountData>,
     account_id_or_address: web::Path<String>,
-) -> ApiResult<Option<Account>> {
+) -> ApiResult<Account> {
     let start = Instant::now();
     let address_or_id = api_try!(data.parse_account_id_or_address(&account_id_or_address));
     let account_id = api_try!(data.get_id_by_address_or_id(address_or_id).await);
-    let res = if let Some(account_id) = account_id {
-        data.account_finalized_info(account_id).await.into()
-    } else {
-        ApiResult::Ok(None)
+    let res = match account_id {
+        Some(account_id) => data
+            .account_finalized_info(account_id)
+            .await
+            .and_then(|account| {
+                account.ok_or_else(|| Error::from(InvalidDataError::AccountNotFound))
+            })
+            .into(),
+        None => Error::from(InvalidDataError::AccountNotFound).into(),
     };
     metrics::histogram!("api", start.elapsed(), "type" => "v02", "endpoint_name" => "account_finalized_info");
     res
@@ -483,7 +521,8 @@ pub fn api_scope(
 mod tests {
     use super::*;
     use crate::api_server::rest::v02::{
-        test_utils::{deserialize_response_result, TestServerConfig},
+        error::ErrorCode,
+        test_utils::{assert_not_found, deserialize_response_result, TestServerConfig},
         SharedData,
     };
     use num::BigUint;
@@ -609,7 +648,7 @@ mod tests {
         let response = client
             .account_info(&format!("{:?}", address), "finalized")
             .await?;
-        let account_finalized_info: Option<Account> = deserialize_response_result(response)?;
+        let account_finalized_info: Account = deserialize_response_result(response)?;
 
         {
             let mut storage = server.pool.access_storage().await?;
@@ -679,7 +718,7 @@ mod tests {
             account_full_info.committed,
             Some(account_committed_info_by_id)
         );
-        assert_eq!(account_full_info.finalized, account_finalized_info);
+        assert_eq!(account_full_info.finalized, Some(account_finalized_info));
         assert_eq!(account_full_info.depositing, expected_depositing);
 
         let query = PaginationQuery {
@@ -711,6 +750,17 @@ mod tests {
             _ => panic!("account_pending_txs returned L2 tx"),
         }
 
+        // Missing accounts are reported with the 404 status.
+        let unknown_address = format!("{:?}", Address::repeat_byte(0xfe));
+        for path in [
+            format!("/api/v0.2/accounts/{}/committed", unknown_address),
+            format!("/api/v0.2/accounts/{}/finalized", unknown_address),
+            format!("/api/v0.2/accounts/{}/committed", u32::MAX),
+            format!("/api/v0.2/accounts/{}/finalized", u32::MAX),
+        ] {
+            assert_not_found(&server.api_server, &path, ErrorCode::AccountNotFound).await?;
+        }
+
         server.stop().await;
         Ok(())
     }
@@ -41,7 +41,11 @@ pub fn block_info_from_details(details: StorageBlockDetails) -> BlockInfo {
         }),
         block_size: details.block_size as u64,
         commit_tx_hash: details.commit_tx_hash.map(|bytes| H256::from_slice(&bytes)),
+        prove_tx_hash: details.prove_tx_hash.map(|bytes| H256::from_slice(&bytes)),
         verify_tx_hash: details.verify_tx_hash.map(|bytes| H256::from_slice(&bytes)),
+        execute_tx_hash: details
+            .execute_tx_hash
+            .map(|bytes| H256::from_slice(&bytes)),
         committed_at: details.committed_at,
         finalized_at: details.verified_at,
         status,
@@ -104,7 +108,11 @@ impl ApiBlockData {
         &self,
         query: PaginationQuery<ApiEither<BlockNumber>>,
     ) -> Result<Paginated<BlockInfo, BlockNumber>, Error> {
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
         storage.paginate_checked(&query).await
     }
 
@@ -113,7 +121,11 @@ impl ApiBlockData {
         block_number: BlockNumber,
         query: PaginationQuery<ApiEither<TxHash>>,
     ) -> Result<Paginated<Transaction, TxHashSerializeWrapper>, Error> {
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
 
         let new_query = PaginationQuery {
             from: BlockAndTxHash {
@@ -132,7 +144,11 @@ impl ApiBlockData {
         block_number: BlockNumber,
         block_index: u64,
     ) -> Result<Option<TxData>, Error> {
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
         Ok(storage
             .chain()
             .operations_ext_schema()
@@ -178,10 +194,14 @@ async fn block_pagination(
 async fn block_by_position(
     data: web::Data<ApiBlockData>,
     block_position: web::Path<String>,
-) -> ApiResult<Option<BlockInfo>> {
+) -> ApiResult<BlockInfo> {
     let start = Instant::now();
     let block_number = api_try!(data.get_block_number_by_position(&block_position).await);
-    let res = data.block_info(block_number).await.into();
+    let res = data
+        .block_info(block_number)
+        .await
+        .and_then(|block| block.ok_or_else(|| Error::from(InvalidDataError::BlockNotFound)))
+        .into();
     metrics::histogram!("api", start.elapsed(), "type" => "v02", "endpoint_name" => "block_by_position");
     res
 }
@@ -202,10 +222,13 @@ async fn block_transactions(
 async fn transaction_in_block(
     data: web::Data<ApiBlockData>,
     path: web::Path<(BlockNumber, u64)>,
-) -> ApiResult<Option<TxData>> {
+) -> ApiResult<TxData> {
     let start = Instant::now();
     let (block_number, block_index) = *path;
-    let res = api_try!(data.tx_data(block_number, block_index).await);
+    let res = api_try!(data
+        .tx_data(block_number, block_index)
+        .await
+        .and_then(|tx| tx.ok_or_else(|| Error::from(InvalidDataError::TransactionNotFound))));
     metrics::histogram!("api", start.elapsed(), "type" => "v02", "endpoint_name" => "transaction_in_block");
     ApiResult::Ok(res)
 }
@@ -231,7 +254,8 @@ pub fn api_scope(pool: ConnectionPool, cache: BlockDetailsCache) -> Scope {
 mod tests {
     use super::*;
     use crate::api_server::rest::v02::{
-        test_utils::{deserialize_response_result, TestServerConfig},
+        error::ErrorCode,
+        test_utils::{assert_not_found, deserialize_response_result, TestServerConfig},
         SharedData,
     };
     use zksync_api_types::v02::{
@@ -329,8 +353,8 @@ mod tests {
                     expected_tx.block_index.unwrap() as u32,
                 )
                 .await?;
-            let tx: Option<TxData> = deserialize_response_result(response)?;
-            let tx = tx.unwrap().tx;
+            let tx: TxData = deserialize_response_result(response)?;
+            let tx = tx.tx;
             assert_eq!(tx.created_at, Some(expected_tx.created_at));
             assert_eq!(*tx.block_number.unwrap(), expected_tx.block_number as u32);
             assert_eq!(tx.fail_reason, expected_tx.fail_reason);
@@ -339,6 +363,21 @@ mod tests {
             }
         }
 
+        // Missing blocks and transactions are reported with the 404 status.
+        let unknown_block = u32::MAX;
+        assert_not_found(
+            &server,
+            &format!("/api/v0.2/blocks/{}", unknown_block),
+            ErrorCode::BlockNotFound,
+        )
+        .await?;
+        assert_not_found(
+            &server,
+            &format!("/api/v0.2/blocks/1/transactions/{}", u32::MAX),
+            ErrorCode::TransactionNotFound,
+        )
+        .await?;
+
         server.stop().await;
         Ok(())
     }
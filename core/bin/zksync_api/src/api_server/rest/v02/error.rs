@@ -1,7 +1,10 @@
 // Built-in uses
 use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 // External uses
+use actix_web::http::StatusCode;
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use thiserror::Error;
@@ -27,7 +30,11 @@ pub enum ErrorCode {
     PaginationLimitTooBig = 206,
     QueryDeserializationError = 207,
     InvalidNFTTokenId = 208,
+    BlockNotFound = 209,
+    NFTNotFound = 210,
+    TransactionBatchNotFound = 211,
     StorageError = 300,
+    StorageUnavailable = 301,
     TokenNotFound = 500,
     ExternalApiError = 501,
     InternalError = 600,
@@ -42,6 +49,44 @@ pub enum ErrorCode {
     Other = 60_000,
 }
 
+impl ErrorCode {
+    /// HTTP status of the response carrying an error with this code.
+    ///
+    /// The match is intentionally exhaustive, so every new error code has to be mapped explicitly.
+    pub fn http_status(&self) -> StatusCode {
+        match self {
+            Self::AccountNotFound
+            | Self::TransactionNotFound
+            | Self::BlockNotFound
+            | Self::NFTNotFound
+            | Self::TransactionBatchNotFound
+            | Self::TokenNotFound => StatusCode::NOT_FOUND,
+            Self::TokenZeroPriceError
+            | Self::InvalidCurrency
+            | Self::InvalidBlockPosition
+            | Self::InvalidAccountIdOrAddress
+            | Self::PaginationLimitTooBig
+            | Self::QueryDeserializationError
+            | Self::InvalidNFTTokenId
+            | Self::AccountCloseDisabled
+            | Self::InvalidParams
+            | Self::UnsupportedFastProcessing
+            | Self::IncorrectTx
+            | Self::TxAddError
+            | Self::InappropriateFeeToken
+            | Self::Toggle2FAError => StatusCode::BAD_REQUEST,
+            Self::StorageUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::UnreacheableError
+            | Self::CoreApiError
+            | Self::ExternalApiError
+            | Self::StorageError
+            | Self::InternalError
+            | Self::CommunicationCoreServer
+            | Self::Other => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
 /// Error object in a response
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -80,9 +125,49 @@ impl Error {
         Error::from(StorageError::new(err))
     }
 
+    /// Error of acquiring a connection to the storage. Unlike other storage errors,
+    /// it's temporary, so clients may retry the request.
+    pub fn storage_unavailable(err: impl Display) -> Error {
+        Error::from(StorageUnavailableError::new(err))
+    }
+
     pub fn core_api(err: impl Display) -> Error {
         Error::from(CoreApiError::new(err))
     }
+
+    /// Returns the error as it's shown to clients: messages of the server errors are made opaque.
+    pub fn into_public(self, resource: &str) -> Error {
+        if self.code.http_status().is_server_error() {
+            self.into_opaque(resource)
+        } else {
+            self
+        }
+    }
+
+    /// Replaces the error message with an opaque reference id, so the details of internal errors
+    /// don't leak to clients. The id is recorded in the request span (see `request_span`),
+    /// and the original error is logged within this span.
+    pub fn into_opaque(self, resource: &str) -> Error {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let reference_id = format!(
+            "{:x}-{:x}",
+            Utc::now().timestamp_millis(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+        tracing::Span::current().record("reference_id", &tracing::field::display(&reference_id));
+        vlog::error!(
+            "Internal error on `{}`, reference id {}: {:?}",
+            resource,
+            reference_id,
+            self
+        );
+        Error {
+            error_type: self.error_type,
+            code: self.code,
+            message: format!("Internal error, reference id: {}", reference_id),
+        }
+    }
 }
 
 #[derive(Error, Debug)]
@@ -103,6 +188,12 @@ pub enum InvalidDataError {
     PaginationLimitTooBig,
     #[error("NFT token ID should be greater than or equal to {}", MIN_NFT_TOKEN_ID)]
     InvalidNFTTokenId,
+    #[error("Block is not found")]
+    BlockNotFound,
+    #[error("NFT is not found")]
+    NFTNotFound,
+    #[error("Transaction batch is not found")]
+    TransactionBatchNotFound,
 }
 
 impl ApiError for InvalidDataError {
@@ -120,6 +211,9 @@ impl ApiError for InvalidDataError {
             Self::TransactionNotFound => ErrorCode::TransactionNotFound,
             Self::PaginationLimitTooBig => ErrorCode::PaginationLimitTooBig,
             Self::InvalidNFTTokenId => ErrorCode::InvalidNFTTokenId,
+            Self::BlockNotFound => ErrorCode::BlockNotFound,
+            Self::NFTNotFound => ErrorCode::NFTNotFound,
+            Self::TransactionBatchNotFound => ErrorCode::TransactionBatchNotFound,
         }
     }
 }
@@ -149,6 +243,31 @@ impl ApiError for StorageError {
     }
 }
 
+#[derive(Debug)]
+pub struct StorageUnavailableError(String);
+
+impl StorageUnavailableError {
+    pub fn new(title: impl Display) -> Self {
+        Self(title.to_string())
+    }
+}
+
+impl Display for StorageUnavailableError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl ApiError for StorageUnavailableError {
+    fn error_type(&self) -> String {
+        String::from("storageError")
+    }
+
+    fn code(&self) -> ErrorCode {
+        ErrorCode::StorageUnavailable
+    }
+}
+
 #[derive(Debug)]
 pub struct CoreApiError(String);
 
@@ -229,3 +348,139 @@ impl ApiError for UnknownFromParameter {
         ErrorCode::QueryDeserializationError
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn error_http_status() {
+        let cases = vec![
+            (
+                Error::from(InvalidDataError::AccountNotFound),
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                Error::from(InvalidDataError::TransactionNotFound),
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                Error::from(InvalidDataError::BlockNotFound),
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                Error::from(InvalidDataError::NFTNotFound),
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                Error::from(InvalidDataError::TransactionBatchNotFound),
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                Error::from(InvalidDataError::InvalidBlockPosition),
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                Error::from(InvalidDataError::PaginationLimitTooBig),
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                Error::from(SubmitError::InappropriateFeeToken),
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                Error::from(SubmitError::Internal(anyhow::anyhow!("internal"))),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                Error::storage_unavailable("db is down"),
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            (
+                Error::storage("error returned from database: syntax error"),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                Error::core_api("core is down"),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+            (
+                Error::from(anyhow::anyhow!("other")),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            ),
+        ];
+
+        for (error, expected_status) in cases {
+            assert_eq!(error.code.http_status(), expected_status, "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn opaque_error() {
+        let error = Error::from(anyhow::anyhow!("connection refused: 127.0.0.1:5432"));
+        let first = error.clone().into_opaque("/api/v0.2/blocks/1");
+        let second = error.clone().into_opaque("/api/v0.2/blocks/1");
+
+        assert_eq!(first.code, error.code);
+        assert_eq!(first.error_type, error.error_type);
+        assert!(!first.message.contains("127.0.0.1"));
+        assert!(first.message.starts_with("Internal error, reference id: "));
+        assert_ne!(first.message, second.message);
+    }
+
+    #[test]
+    fn public_error() {
+        let storage_error = Error::storage("relation \"balances\" does not exist");
+        let public = storage_error.clone().into_public("/api/v0.2/tokens/0");
+        assert_eq!(public.code, ErrorCode::StorageError);
+        assert!(!public.message.contains("balances"));
+
+        let not_found = Error::from(InvalidDataError::AccountNotFound);
+        assert_eq!(
+            not_found.clone().into_public("/api/v0.2/accounts/1"),
+            not_found
+        );
+    }
+
+    #[test]
+    fn opaque_error_reference_id_in_span() {
+        #[derive(Clone, Default)]
+        struct LogsBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl io::Write for LogsBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let logs = LogsBuffer::default();
+        let writer = logs.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let error = tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("api_v02_request", reference_id = tracing::field::Empty);
+            let _entered = span.enter();
+            Error::from(anyhow::anyhow!("other")).into_opaque("/api/v0.2/blocks/1")
+        });
+
+        let reference_id = error
+            .message
+            .strip_prefix("Internal error, reference id: ")
+            .unwrap();
+        let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        assert!(
+            logs.contains(&format!("reference_id={}", reference_id)),
+            "{}",
+            logs
+        );
+    }
+}
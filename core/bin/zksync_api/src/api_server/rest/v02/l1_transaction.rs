@@ -0,0 +1,236 @@
+//! L1 transactions part of API implementation.
+
+// Built-in uses
+use std::convert::TryFrom;
+use std::str::FromStr;
+use std::time::Instant;
+
+// External uses
+use actix_web::{web, Scope};
+
+// Workspace uses
+use zksync_api_types::v02::l1_transaction::L1TransactionInfo;
+use zksync_storage::ConnectionPool;
+use zksync_types::{
+    aggregated_operations::AggregatedActionType, BlockNumber, ExecutedOperations, H256,
+};
+
+// Local uses
+use super::{
+    block::block_info_from_details,
+    error::{Error, InvalidDataError},
+    response::ApiResult,
+};
+
+/// Shared data between `api/v0.2/l1Transactions` endpoints.
+#[derive(Debug, Clone)]
+struct ApiL1TransactionData {
+    pool: ConnectionPool,
+}
+
+impl ApiL1TransactionData {
+    fn new(pool: ConnectionPool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns the aggregated operation sent within the Ethereum transaction with the given hash.
+    async fn l1_transaction(&self, tx_hash: H256) -> Result<L1TransactionInfo, Error> {
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
+        let (op, final_tx_hash) = storage
+            .ethereum_schema()
+            .aggregated_op_by_tx_hash(&tx_hash)
+            .await
+            .map_err(Error::storage)?
+            .ok_or_else(|| Error::from(InvalidDataError::TransactionNotFound))?;
+
+        // The stored operation is expected to be valid, so conversion failures
+        // mean corrupted data rather than a storage problem.
+        let operation_type = AggregatedActionType::from_str(&op.action_type)
+            .map_err(|err| Error::from(anyhow::anyhow!(err)))?;
+        let from_block = u32::try_from(op.from_block)
+            .map(BlockNumber)
+            .map_err(|err| Error::from(anyhow::Error::from(err)))?;
+        let to_block = u32::try_from(op.to_block)
+            .map(BlockNumber)
+            .map_err(|err| Error::from(anyhow::Error::from(err)))?;
+
+        let blocks = storage
+            .chain()
+            .block_schema()
+            .load_block_range_asc(from_block, *to_block - *from_block + 1)
+            .await
+            .map_err(Error::storage)?
+            .into_iter()
+            .map(block_info_from_details)
+            .collect();
+
+        let withdrawals = if matches!(operation_type, AggregatedActionType::ExecuteBlocks) {
+            let mut withdrawals = Vec::new();
+            for block_number in *from_block..=*to_block {
+                let executed_ops = storage
+                    .chain()
+                    .block_schema()
+                    .get_block_executed_ops(BlockNumber(block_number))
+                    .await
+                    .map_err(Error::storage)?;
+                withdrawals.extend(
+                    executed_ops
+                        .into_iter()
+                        .filter(|op| {
+                            op.get_executed_op()
+                                .map(|op| op.is_processable_onchain_operation())
+                                .unwrap_or(false)
+                        })
+                        .map(|op| match op {
+                            ExecutedOperations::Tx(tx) => tx.signed_tx.tx.hash(),
+                            ExecutedOperations::PriorityOp(op) => op.priority_op.tx_hash(),
+                        }),
+                );
+            }
+            Some(withdrawals)
+        } else {
+            None
+        };
+
+        Ok(L1TransactionInfo {
+            final_tx_hash,
+            operation_type,
+            from_block,
+            to_block,
+            confirmed: op.confirmed,
+            blocks,
+            withdrawals,
+        })
+    }
+}
+
+// Server implementation
+
+async fn l1_transaction(
+    data: web::Data<ApiL1TransactionData>,
+    tx_hash: web::Path<H256>,
+) -> ApiResult<L1TransactionInfo> {
+    let start = Instant::now();
+    let res = data.l1_transaction(*tx_hash).await.into();
+    metrics::histogram!("api", start.elapsed(), "type" => "v02", "endpoint_name" => "l1_transaction");
+    res
+}
+
+pub fn api_scope(pool: ConnectionPool) -> Scope {
+    let data = ApiL1TransactionData::new(pool);
+
+    web::scope("l1Transactions")
+        .app_data(web::Data::new(data))
+        .route("{tx_hash}", web::get().to(l1_transaction))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_server::rest::v02::{
+        error::ErrorCode,
+        test_utils::{assert_not_found, deserialize_response_result, TestServerConfig},
+        SharedData,
+    };
+    use zksync_api_types::v02::ApiVersion;
+    use zksync_storage::{chain::operations::OperationsSchema, test_data::dummy_ethereum_tx_hash};
+
+    #[actix_rt::test]
+    #[cfg_attr(
+        not(feature = "api_test"),
+        ignore = "Use `zk test rust-api` command to perform this test"
+    )]
+    async fn l1_transactions_scope() -> anyhow::Result<()> {
+        let cfg = TestServerConfig::default();
+        cfg.fill_database().await?;
+
+        let shared_data = SharedData {
+            net: cfg.config.chain.eth.network,
+            api_version: ApiVersion::V02,
+        };
+        let (client, server) = cfg.start_server(
+            |cfg: &TestServerConfig| api_scope(cfg.pool.clone()),
+            Some(shared_data),
+        );
+
+        let block_number = BlockNumber(1);
+        let (commit_op_id, prove_op_id, execute_op_id) = {
+            let mut storage = cfg.pool.access_storage().await?;
+            let (commit_op_id, _) = OperationsSchema(&mut storage)
+                .get_aggregated_op_that_affects_block(
+                    AggregatedActionType::CommitBlocks,
+                    block_number,
+                )
+                .await?
+                .unwrap();
+            let (prove_op_id, _) = OperationsSchema(&mut storage)
+                .get_aggregated_op_that_affects_block(
+                    AggregatedActionType::PublishProofBlocksOnchain,
+                    block_number,
+                )
+                .await?
+                .unwrap();
+            let (execute_op_id, _) = OperationsSchema(&mut storage)
+                .get_aggregated_op_that_affects_block(
+                    AggregatedActionType::ExecuteBlocks,
+                    block_number,
+                )
+                .await?
+                .unwrap();
+            (commit_op_id, prove_op_id, execute_op_id)
+        };
+
+        let commit_tx_hash = dummy_ethereum_tx_hash(commit_op_id);
+        let response = client.l1_transaction(commit_tx_hash).await?;
+        let info: L1TransactionInfo = deserialize_response_result(response)?;
+        assert!(matches!(
+            info.operation_type,
+            AggregatedActionType::CommitBlocks
+        ));
+        assert_eq!(info.final_tx_hash, Some(commit_tx_hash));
+        assert_eq!(info.from_block, block_number);
+        assert_eq!(info.to_block, block_number);
+        assert_eq!(info.blocks.len(), 1);
+        assert_eq!(info.blocks[0].block_number, block_number);
+        assert_eq!(info.blocks[0].commit_tx_hash, Some(commit_tx_hash));
+        assert!(info.withdrawals.is_none());
+
+        let prove_tx_hash = dummy_ethereum_tx_hash(prove_op_id);
+        let response = client.l1_transaction(prove_tx_hash).await?;
+        let info: L1TransactionInfo = deserialize_response_result(response)?;
+        assert!(matches!(
+            info.operation_type,
+            AggregatedActionType::PublishProofBlocksOnchain
+        ));
+        assert_eq!(info.blocks[0].commit_tx_hash, Some(commit_tx_hash));
+        assert_eq!(info.blocks[0].prove_tx_hash, Some(prove_tx_hash));
+        assert!(info.withdrawals.is_none());
+
+        let execute_tx_hash = dummy_ethereum_tx_hash(execute_op_id);
+        let response = client.l1_transaction(execute_tx_hash).await?;
+        let info: L1TransactionInfo = deserialize_response_result(response)?;
+        assert!(matches!(
+            info.operation_type,
+            AggregatedActionType::ExecuteBlocks
+        ));
+        assert_eq!(info.blocks[0].verify_tx_hash, Some(execute_tx_hash));
+        assert_eq!(info.blocks[0].execute_tx_hash, Some(execute_tx_hash));
+        assert!(info.withdrawals.is_some());
+
+        let response = client.l1_transaction(H256::repeat_byte(0xff)).await?;
+        assert!(deserialize_response_result::<L1TransactionInfo>(response).is_err());
+        assert_not_found(
+            &server,
+            &format!("/api/v0.2/l1Transactions/{:?}", H256::repeat_byte(0xff)),
+            ErrorCode::TransactionNotFound,
+        )
+        .await?;
+
+        server.stop().await;
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 // External uses
 use actix_web::{
+    dev::ServiceRequest,
     web::{self},
     Scope,
 };
@@ -17,6 +18,7 @@ mod block;
 mod config;
 pub mod error;
 mod fee;
+mod l1_transaction;
 mod paginate_impl;
 mod paginate_trait;
 mod response;
@@ -32,6 +34,19 @@ pub struct SharedData {
     pub api_version: ApiVersion,
 }
 
+/// Creates the tracing span of the request.
+///
+/// Reference ids of the opaque errors are recorded in this span, so operators can find the logs
+/// of the request that failed.
+pub(crate) fn request_span(req: &ServiceRequest) -> tracing::Span {
+    tracing::info_span!(
+        "api_v02_request",
+        method = %req.method(),
+        path = %req.path(),
+        reference_id = tracing::field::Empty,
+    )
+}
+
 pub(crate) fn api_scope(
     tx_sender: TxSender,
     zk_config: &ZkSyncConfig,
@@ -54,6 +69,7 @@ pub(crate) fn api_scope(
         ))
         .service(config::api_scope(zk_config))
         .service(fee::api_scope(tx_sender.clone()))
+        .service(l1_transaction::api_scope(tx_sender.pool.clone()))
         .service(status::api_scope(network_status))
         .service(token::api_scope(
             zk_config,
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use std::convert::From;
 
 // External uses
-use actix_web::{web::Data, HttpRequest, HttpResponse, Responder};
+use actix_web::{http::StatusCode, web::Data, HttpRequest, HttpResponse, Responder};
 use chrono::Utc;
 use qstring::QString;
 use serde::{Deserialize, Serialize};
@@ -45,24 +45,34 @@ impl<R: Serialize> Responder for ApiResult<R> {
             timestamp: Utc::now(),
         };
 
-        let response = match self {
-            ApiResult::Ok(res) => Response {
-                request,
-                status: ResultStatus::Success,
-                result: Some(serde_json::to_value(res).unwrap()),
-                error: None,
-            },
-            ApiResult::Error(err) => Response {
-                request,
-                status: ResultStatus::Error,
-                result: None,
-                error: Some(serde_json::to_value(err).unwrap()),
-            },
+        let (http_status, response) = match self {
+            ApiResult::Ok(res) => (
+                StatusCode::OK,
+                Response {
+                    request,
+                    status: ResultStatus::Success,
+                    result: Some(serde_json::to_value(res).unwrap()),
+                    error: None,
+                },
+            ),
+            ApiResult::Error(err) => {
+                let http_status = err.code.http_status();
+                let err = err.into_public(&request.resource);
+                (
+                    http_status,
+                    Response {
+                        request,
+                        status: ResultStatus::Error,
+                        result: None,
+                        error: Some(serde_json::to_value(err).unwrap()),
+                    },
+                )
+            }
         };
 
         let body = serde_json::to_string(&response).expect("Should be correct serializable");
 
-        HttpResponse::Ok()
+        HttpResponse::build(http_status)
             .content_type("application/json")
             .body(body)
     }
@@ -94,3 +104,47 @@ macro_rules! api_try {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    /// Checks that handlers don't build HTTP responses on their own, bypassing the status mapping
+    /// of `ApiResult`.
+    #[test]
+    fn handlers_use_api_result() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/api_server/rest/v02");
+        let mut checked = Vec::new();
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let file_name = path.file_name().unwrap().to_string_lossy().into_owned();
+            if file_name == "response.rs" {
+                continue;
+            }
+            let source = std::fs::read_to_string(&path).unwrap();
+            assert!(
+                !source.contains("HttpResponse"),
+                "{} constructs `HttpResponse` directly",
+                path.display()
+            );
+            checked.push(file_name);
+        }
+
+        for handler in [
+            "account.rs",
+            "block.rs",
+            "config.rs",
+            "fee.rs",
+            "l1_transaction.rs",
+            "status.rs",
+            "token.rs",
+            "transaction.rs",
+        ] {
+            assert!(
+                checked.iter().any(|file_name| file_name == handler),
+                "{} is not audited",
+                handler
+            );
+        }
+    }
+}
@@ -46,6 +46,7 @@ use zksync_types::{
 use zksync_utils::{big_decimal_to_ratio, scaled_u64_to_ratio, UnsignedRatioSerializeAsDecimal};
 
 // Local uses
+use super::error::ErrorCode;
 use crate::fee_ticker::{
     tests::TestToken,
     ticker_info::BlocksInFutureAggregatedOperations,
@@ -797,6 +798,27 @@ pub fn dummy_full_exit_op(
     }
 }
 
+/// Requests a missing resource from the test server and checks that it's reported
+/// with the 404 status and the given error code.
+pub async fn assert_not_found(
+    server: &actix_test::TestServer,
+    path: &str,
+    code: ErrorCode,
+) -> anyhow::Result<()> {
+    let response = reqwest::get(server.url(path)).await?;
+    assert_eq!(
+        response.status(),
+        reqwest::StatusCode::NOT_FOUND,
+        "{}",
+        path
+    );
+
+    let response: Response = response.json().await?;
+    let error: super::error::Error = serde_json::from_value(response.error.unwrap())?;
+    assert_eq!(error.code, code, "{}", path);
+    Ok(())
+}
+
 pub fn deserialize_response_result<T: DeserializeOwned>(response: Response) -> anyhow::Result<T> {
     match response.result {
         Some(result) => {
@@ -80,7 +80,11 @@ impl ApiTokenData {
         &self,
         query: PaginationQuery<ApiEither<TokenId>>,
     ) -> Result<Paginated<ApiToken, TokenId>, Error> {
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
         let paginated_tokens: Result<Paginated<Token, TokenId>, Error> =
             storage.paginate_checked(&query).await;
         match paginated_tokens {
@@ -124,7 +128,11 @@ impl ApiTokenData {
 
         // Establish db connection and repeat the query, so the token is loaded
         // from the db.
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
 
         let token = self
             .tokens
@@ -142,7 +150,11 @@ impl ApiTokenData {
 
     async fn api_token(&self, token_like: TokenLike) -> Result<ApiToken, Error> {
         let token = self.token(token_like).await?;
-        let mut storage = self.pool.access_storage().await.map_err(Error::storage)?;
+        let mut storage = self
+            .pool
+            .access_storage()
+            .await
+            .map_err(Error::storage_unavailable)?;
         let enabled_for_fees = self
             .is_token_enabled_for_fees(&mut storage, token.id)
             .await?;
@@ -235,20 +247,22 @@ async fn token_price(
     })
 }
 
-async fn get_nft(
-    data: web::Data<ApiTokenData>,
-    id: web::Path<TokenId>,
-) -> ApiResult<Option<ApiNFT>> {
+async fn get_nft(data: web::Data<ApiTokenData>, id: web::Path<TokenId>) -> ApiResult<ApiNFT> {
     let start = Instant::now();
     if id.0 < MIN_NFT_TOKEN_ID {
         return Error::from(InvalidDataError::InvalidNFTTokenId).into();
     }
-    let mut storage = api_try!(data.pool.access_storage().await.map_err(Error::storage));
+    let mut storage = api_try!(data
+        .pool
+        .access_storage()
+        .await
+        .map_err(Error::storage_unavailable));
     let nft = api_try!(storage
         .tokens_schema()
         .get_nft_with_factories(*id)
         .await
-        .map_err(Error::storage));
+        .map_err(Error::storage)
+        .and_then(|nft| nft.ok_or_else(|| Error::from(InvalidDataError::NFTNotFound))));
     metrics::histogram!("api", start.elapsed(), "type" => "v02", "endpoint_name" => "get_nft");
     ApiResult::Ok(nft)
 }
@@ -256,18 +270,23 @@ async fn get_nft(
 async fn get_nft_owner(
     data: web::Data<ApiTokenData>,
     id: web::Path<TokenId>,
-) -> ApiResult<Option<AccountId>> {
+) -> ApiResult<AccountId> {
     let start = Instant::now();
     if id.0 < MIN_NFT_TOKEN_ID {
         return Error::from(InvalidDataError::InvalidNFTTokenId).into();
     }
-    let mut storage = api_try!(data.pool.access_storage().await.map_err(Error::storage));
+    let mut storage = api_try!(data
+        .pool
+        .access_storage()
+        .await
+        .map_err(Error::storage_unavailable));
     let owner_id = api_try!(storage
         .chain()
         .account_schema()
         .get_nft_owner(*id)
         .await
-        .map_err(Error::storage));
+        .map_err(Error::storage)
+        .and_then(|owner_id| owner_id.ok_or_else(|| Error::from(InvalidDataError::NFTNotFound))));
     metrics::histogram!("api", start.elapsed(), "type" => "v02", "endpoint_name" => "get_nft_owner");
     ApiResult::Ok(owner_id)
 }
@@ -275,15 +294,20 @@ async fn get_nft_owner(
 async fn get_nft_id_by_tx_hash(
     data: web::Data<ApiTokenData>,
     tx_hash: web::Path<TxHash>,
-) -> ApiResult<Option<TokenId>> {
+) -> ApiResult<TokenId> {
     let start = Instant::now();
-    let mut storage = api_try!(data.pool.access_storage().await.map_err(Error::storage));
+    let mut storage = api_try!(data
+        .pool
+        .access_storage()
+        .await
+        .map_err(Error::storage_unavailable));
     let nft_id = api_try!(storage
         .chain()
         .state_schema()
         .get_nft_id_by_tx_hash(*tx_hash)
         .await
-        .map_err(Error::storage));
+        .map_err(Error::storage)
+        .and_then(|nft_id| nft_id.ok_or_else(|| Error::from(InvalidDataError::NFTNotFound))));
     metrics::histogram!("api", start.elapsed(), "type" => "v02", "endpoint_name" => "get_nft_id_by_tx_hash");
     ApiResult::Ok(nft_id)
 }
@@ -316,7 +340,10 @@ pub fn api_scope(
 mod tests {
     use super::*;
     use crate::api_server::rest::v02::{
-        test_utils::{deserialize_response_result, dummy_fee_ticker, TestServerConfig},
+        error::ErrorCode,
+        test_utils::{
+            assert_not_found, deserialize_response_result, dummy_fee_ticker, TestServerConfig,
+        },
         SharedData,
     };
     use zksync_api_types::v02::{pagination::PaginationDirection, ApiVersion};
@@ -497,8 +524,21 @@ mod tests {
         };
 
         let response = client.nft_id_by_tx_hash(tx_hash).await?;
-        let nft_id: Option<TokenId> = deserialize_response_result(response)?;
-        assert!(nft_id.is_some());
+        let _nft_id: TokenId = deserialize_response_result(response)?;
+
+        // Missing NFTs are reported with the 404 status.
+        let unknown_nft_id = u32::MAX;
+        let unknown_tx_hash = TestServerConfig::gen_zk_txs(1_u64).txs[0].0.hash();
+        for path in [
+            format!("/api/v0.2/tokens/nft/{}", unknown_nft_id),
+            format!("/api/v0.2/tokens/nft/{}/owner", unknown_nft_id),
+            format!(
+                "/api/v0.2/tokens/nft_id_by_tx_hash/{}",
+                unknown_tx_hash.to_string()
+            ),
+        ] {
+            assert_not_found(&server, &path, ErrorCode::NFTNotFound).await?;
+        }
 
         server.stop().await;
         Ok(())
@@ -20,7 +20,10 @@ use zksync_api_types::{
 use zksync_types::{tx::TxHash, EthBlockId};
 
 // Local uses
-use super::{error::Error, response::ApiResult};
+use super::{
+    error::{Error, InvalidDataError},
+    response::ApiResult,
+};
 use crate::api_server::tx_sender::{SubmitError, TxSender};
 
 /// Shared data between `api/v0.2/transactions` endpoints.
@@ -41,7 +44,7 @@ impl ApiTransactionData {
             .pool
             .access_storage()
             .await
-            .map_err(Error::storage)?;
+            .map_err(Error::storage_unavailable)?;
 
         // 1. Try to find the already received/executed operation.
         if let Some(receipt) = storage
@@ -80,7 +83,7 @@ impl ApiTransactionData {
             .pool
             .access_storage()
             .await
-            .map_err(Error::storage)?;
+            .map_err(Error::storage_unavailable)?;
         if let Some(data) = storage
             .chain()
             .operations_ext_schema()
@@ -128,7 +131,7 @@ impl ApiTransactionData {
             .pool
             .access_storage()
             .await
-            .map_err(Error::storage)?;
+            .map_err(Error::storage_unavailable)?;
         storage
             .chain()
             .operations_ext_schema()
@@ -143,9 +146,15 @@ impl ApiTransactionData {
 async fn tx_status(
     data: web::Data<ApiTransactionData>,
     tx_hash: web::Path<TxHash>,
-) -> ApiResult<Option<Receipt>> {
+) -> ApiResult<Receipt> {
     let start = Instant::now();
-    let res = data.tx_status(*tx_hash).await.into();
+    let res = data
+        .tx_status(*tx_hash)
+        .await
+        .and_then(|receipt| {
+            receipt.ok_or_else(|| Error::from(InvalidDataError::TransactionNotFound))
+        })
+        .into();
     metrics::histogram!("api", start.elapsed(), "type" => "v02", "endpoint_name" => "tx_status");
     res
 }
@@ -153,9 +162,13 @@ async fn tx_status(
 async fn tx_data(
     data: web::Data<ApiTransactionData>,
     tx_hash: web::Path<TxHash>,
-) -> ApiResult<Option<TxData>> {
+) -> ApiResult<TxData> {
     let start = Instant::now();
-    let res = data.tx_data(*tx_hash).await.into();
+    let res = data
+        .tx_data(*tx_hash)
+        .await
+        .and_then(|tx| tx.ok_or_else(|| Error::from(InvalidDataError::TransactionNotFound)))
+        .into();
     metrics::histogram!("api", start.elapsed(), "type" => "v02", "endpoint_name" => "tx_data");
     res
 }
@@ -228,9 +241,15 @@ async fn toggle_2fa(
 async fn get_batch(
     data: web::Data<ApiTransactionData>,
     batch_hash: web::Path<TxHash>,
-) -> ApiResult<Option<ApiTxBatch>> {
+) -> ApiResult<ApiTxBatch> {
     let start = Instant::now();
-    let res = data.get_batch(*batch_hash).await.into();
+    let res = data
+        .get_batch(*batch_hash)
+        .await
+        .and_then(|batch| {
+            batch.ok_or_else(|| Error::from(InvalidDataError::TransactionBatchNotFound))
+        })
+        .into();
     metrics::histogram!("api", start.elapsed(), "type" => "v02", "endpoint_name" => "get_batch");
     res
 }
@@ -252,9 +271,10 @@ pub fn api_scope(tx_sender: TxSender) -> Scope {
 mod tests {
     use super::*;
     use crate::api_server::rest::v02::{
+        error::ErrorCode,
         test_utils::{
-            deserialize_response_result, dummy_fee_ticker, dummy_sign_verifier, TestServerConfig,
-            TestTransactions,
+            assert_not_found, deserialize_response_result, dummy_fee_ticker, dummy_sign_verifier,
+            TestServerConfig, TestTransactions,
         },
         SharedData,
     };
@@ -460,8 +480,8 @@ mod tests {
         assert_eq!(tx_status, expected_tx_status);
 
         let response = client.tx_data(tx_hash).await?;
-        let tx_data: Option<TxData> = deserialize_response_result(response)?;
-        assert_eq!(tx_data.unwrap().tx.tx_hash, tx_hash);
+        let tx_data: TxData = deserialize_response_result(response)?;
+        assert_eq!(tx_data.tx.tx_hash, tx_hash);
 
         let pending_tx_hash = {
             let mut storage = cfg.pool.access_storage().await?;
@@ -491,13 +511,33 @@ mod tests {
         assert_eq!(tx_status, expected_tx_status);
 
         let response = client.tx_data(pending_tx_hash).await?;
-        let tx_data: Option<TxData> = deserialize_response_result(response)?;
-        assert_eq!(tx_data.unwrap().tx.tx_hash, pending_tx_hash);
-
-        let tx = TestServerConfig::gen_zk_txs(1_u64).txs[0].0.clone();
-        let response = client.tx_data(tx.hash()).await?;
-        let tx_data: Option<TxData> = deserialize_response_result(response)?;
-        assert!(tx_data.is_none());
+        let tx_data: TxData = deserialize_response_result(response)?;
+        assert_eq!(tx_data.tx.tx_hash, pending_tx_hash);
+
+        // Missing transactions and batches are reported with the 404 status.
+        let unknown_tx_hash = TestServerConfig::gen_zk_txs(1_u64).txs[0].0.hash();
+        for (path, code) in [
+            (
+                format!("/api/v0.2/transactions/{}", unknown_tx_hash.to_string()),
+                ErrorCode::TransactionNotFound,
+            ),
+            (
+                format!(
+                    "/api/v0.2/transactions/{}/data",
+                    unknown_tx_hash.to_string()
+                ),
+                ErrorCode::TransactionNotFound,
+            ),
+            (
+                format!(
+                    "/api/v0.2/transactions/batches/{}",
+                    unknown_tx_hash.to_string()
+                ),
+                ErrorCode::TransactionBatchNotFound,
+            ),
+        ] {
+            assert_not_found(&server, &path, code).await?;
+        }
 
         server.stop().await;
         task.abort();
@@ -3,19 +3,24 @@ use std::collections::HashMap;
 use std::convert::TryInto;
 use std::path::PathBuf;
 use std::str::FromStr;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 // External uses
 use ethabi::{encode, Contract, Function, Token as AbiToken};
 use jsonrpc_core::{Error, ErrorCode, Result};
+use num::BigUint;
+use serde_json::Value;
 use tiny_keccak::keccak256;
+use tokio::sync::RwLock;
 // Workspace uses
 use zksync_storage::StorageProcessor;
 use zksync_token_db_cache::TokenDBCache;
-use zksync_types::{TokenId, TokenKind, NFT};
+use zksync_types::{Token, TokenId, TokenKind, NFT};
 
 // Local uses
 use super::{
     converter::u256_from_biguint,
+    error::internal_error,
     types::{H160, U256},
     NFT_FACTORY_ADDRESS, ZKSYNC_PROXY_ADDRESS,
 };
@@ -27,6 +32,9 @@ pub struct CallsHelper {
     erc20: HashMap<Selector, Function>,
     nft_factory: HashMap<Selector, Function>,
     tokens: TokenDBCache,
+    /// Total supplies are summed over all the balances, so they are cached like the tokens.
+    total_supplies: Arc<RwLock<HashMap<TokenId, (BigUint, Instant)>>>,
+    invalidate_cache_period: Duration,
     zksync_proxy_address: H160,
     nft_factory_address: H160,
 }
@@ -35,11 +43,18 @@ impl CallsHelper {
     const SHA256_MULTI_HASH: [u8; 2] = [18, 32]; // 0x1220
     const ALPHABET: &'static str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
 
-    fn revert_error(message: &str) -> Error {
+    /// Selector of the Solidity `Error(string)` used to encode revert reasons.
+    const REVERT_REASON_SELECTOR: Selector = [0x08, 0xc3, 0x79, 0xa0];
+
+    /// Creates an "execution reverted" error with the reason ABI-encoded as `Error(string)`
+    /// in the error data, so that clients can decode it the same way as Solidity reverts.
+    fn revert_error(reason: &str) -> Error {
+        let mut data = Self::REVERT_REASON_SELECTOR.to_vec();
+        data.extend(encode(&[AbiToken::String(reason.to_string())]));
         Error {
             code: ErrorCode::ServerError(3),
-            message: message.to_string(),
-            data: None,
+            message: format!("execution reverted: {}", reason),
+            data: Some(Value::String(format!("0x{}", hex::encode(data)))),
         }
     }
 
@@ -88,48 +103,84 @@ impl CallsHelper {
             erc20: erc20_function_by_selector,
             nft_factory: nft_factory_function_by_selector,
             tokens: TokenDBCache::new(invalidate_token_cache_period),
+            total_supplies: Default::default(),
+            invalidate_cache_period: invalidate_token_cache_period,
             zksync_proxy_address: H160::from_str(ZKSYNC_PROXY_ADDRESS).unwrap(),
             nft_factory_address: H160::from_str(NFT_FACTORY_ADDRESS).unwrap(),
         }
     }
 
+    /// Returns the sum of the token balances over all the accounts, using the total supplies cache.
+    async fn token_total_supply(
+        &self,
+        storage: &mut StorageProcessor<'_>,
+        token_id: TokenId,
+    ) -> Result<BigUint> {
+        if let Some((total_supply, update_time)) = self.total_supplies.read().await.get(&token_id) {
+            if update_time.elapsed() < self.invalidate_cache_period {
+                return Ok(total_supply.clone());
+            }
+        }
+        let total_supply = storage
+            .chain()
+            .account_schema()
+            .get_token_total_supply(token_id)
+            .await
+            .map_err(internal_error)?;
+        self.total_supplies
+            .write()
+            .await
+            .insert(token_id, (total_supply.clone(), Instant::now()));
+        Ok(total_supply)
+    }
+
+    /// Returns the token with the given L1 address, using the tokens cache.
+    pub async fn token_by_address(
+        &self,
+        storage: &mut StorageProcessor<'_>,
+        address: H160,
+    ) -> Result<Option<Token>> {
+        self.tokens
+            .get_token(storage, address)
+            .await
+            .map_err(internal_error)
+    }
+
     pub async fn execute(
         &self,
         storage: &mut StorageProcessor<'_>,
         to: H160,
         data: Vec<u8>,
     ) -> Result<Vec<u8>> {
-        let mut transaction = storage
-            .start_transaction()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
         let all_functions = if to == self.nft_factory_address {
             &self.nft_factory
         } else {
-            let token = self
-                .tokens
-                .get_token(&mut transaction, to)
-                .await
-                .map_err(|_| Error::internal_error())?;
+            let token = self.token_by_address(&mut transaction, to).await?;
             match token {
                 Some(token) if matches!(token.kind, TokenKind::ERC20) => &self.erc20,
-                _ => return Ok(Vec::new()),
+                _ => return Err(Self::revert_error("call to an unsupported contract")),
             }
         };
         let selector: Selector = if data.len() >= 4 {
             data[0..4].try_into().unwrap()
         } else {
-            return Ok(Vec::new());
+            return Err(Self::revert_error(
+                "call data is shorter than a function selector",
+            ));
         };
         let function = if let Some(function) = all_functions.get(&selector) {
             function
         } else {
-            return Ok(Vec::new());
+            return Err(Self::revert_error("function selector is not supported"));
         };
         let params = if let Ok(params) = function.decode_input(&data[4..]) {
             params
         } else {
-            return Ok(Vec::new());
+            return Err(Self::revert_error(&format!(
+                "invalid input for function `{}`",
+                function.name
+            )));
         };
 
         let result = if to == self.nft_factory_address {
@@ -142,9 +193,7 @@ impl CallsHelper {
                     if let Some(nft) = self.get_nft(&mut transaction, token_id).await? {
                         encode(&[AbiToken::Uint(U256::from(nft.creator_id.0))])
                     } else {
-                        return Err(Self::revert_error(
-                            "execution reverted: creator ID query for nonexistent token",
-                        ));
+                        return Err(Self::revert_error("creator ID query for nonexistent token"));
                     }
                 }
                 "creatorAddress" => {
@@ -156,7 +205,7 @@ impl CallsHelper {
                         encode(&[AbiToken::Address(nft.creator_address)])
                     } else {
                         return Err(Self::revert_error(
-                            "execution reverted: creator address query for nonexistent token",
+                            "creator address query for nonexistent token",
                         ));
                     }
                 }
@@ -168,9 +217,7 @@ impl CallsHelper {
                     if let Some(nft) = self.get_nft(&mut transaction, token_id).await? {
                         encode(&[AbiToken::Uint(U256::from(nft.serial_id))])
                     } else {
-                        return Err(Self::revert_error(
-                            "execution reverted: serial ID query for nonexistent token",
-                        ));
+                        return Err(Self::revert_error("serial ID query for nonexistent token"));
                     }
                 }
                 "contentHash" => {
@@ -182,7 +229,7 @@ impl CallsHelper {
                         encode(&[AbiToken::FixedBytes(nft.content_hash.as_bytes().to_vec())])
                     } else {
                         return Err(Self::revert_error(
-                            "execution reverted: content hash query for nonexistent token",
+                            "content hash query for nonexistent token",
                         ));
                     }
                 }
@@ -196,7 +243,7 @@ impl CallsHelper {
                         encode(&[AbiToken::String(format!("ipfs://{}", ipfs_cid))])
                     } else {
                         return Err(Self::revert_error(
-                            "execution reverted: ERC721Metadata: URI query for nonexistent token",
+                            "ERC721Metadata: URI query for nonexistent token",
                         ));
                     }
                 }
@@ -207,7 +254,7 @@ impl CallsHelper {
                         .ok_or_else(Error::internal_error)?;
                     if address.is_zero() {
                         return Err(Self::revert_error(
-                            "execution reverted: ERC721: balance query for the zero address",
+                            "ERC721: balance query for the zero address",
                         ));
                     }
                     let balance = transaction
@@ -215,7 +262,7 @@ impl CallsHelper {
                         .account_schema()
                         .get_account_nft_balance(address)
                         .await
-                        .map_err(|_| Error::internal_error())?;
+                        .map_err(internal_error)?;
                     encode(&[AbiToken::Uint(U256::from(balance))])
                 }
                 "ownerOf" => {
@@ -229,14 +276,14 @@ impl CallsHelper {
                             .account_schema()
                             .get_nft_owner(nft.id)
                             .await
-                            .map_err(|_| Error::internal_error())?;
+                            .map_err(internal_error)?;
                         let owner_address = if let Some(owner_id) = owner_id {
                             let owner_address = transaction
                                 .chain()
                                 .account_schema()
                                 .account_address_by_id(owner_id)
                                 .await
-                                .map_err(|_| Error::internal_error())?;
+                                .map_err(internal_error)?;
                             owner_address.unwrap_or_default()
                         } else {
                             H160::zero()
@@ -244,7 +291,7 @@ impl CallsHelper {
                         encode(&[AbiToken::Address(owner_address)])
                     } else {
                         return Err(Self::revert_error(
-                            "execution reverted: ERC721: owner query for nonexistent token",
+                            "ERC721: owner query for nonexistent token",
                         ));
                     }
                 }
@@ -257,7 +304,7 @@ impl CallsHelper {
                         encode(&[AbiToken::Address(self.zksync_proxy_address)])
                     } else {
                         return Err(Self::revert_error(
-                            "execution reverted: ERC721: approved query for nonexistent token",
+                            "ERC721: approved query for nonexistent token",
                         ));
                     }
                 }
@@ -268,19 +315,25 @@ impl CallsHelper {
                 .tokens
                 .get_token(&mut transaction, to)
                 .await
-                .map_err(|_| Error::internal_error())?
+                .map_err(internal_error)?
                 .ok_or_else(Error::internal_error)?;
             match function.name.as_str() {
                 "name" | "symbol" => encode(&[AbiToken::String(token.symbol)]),
                 "decimals" => encode(&[AbiToken::Uint(U256::from(token.decimals))]),
-                "totalSupply" | "allowance" => encode(&[AbiToken::Uint(U256::max_value())]),
+                "totalSupply" => {
+                    let total_supply = self.token_total_supply(&mut transaction, token.id).await?;
+                    encode(&[AbiToken::Uint(u256_from_biguint(total_supply))])
+                }
+                // zkSync has no approvals, tokens are moved only by transactions signed
+                // by their owners, so the allowance is always zero.
+                "allowance" => encode(&[AbiToken::Uint(U256::zero())]),
                 "balanceOf" => {
                     let block = transaction
                         .chain()
                         .block_schema()
                         .get_last_verified_confirmed_block()
                         .await
-                        .map_err(|_| Error::internal_error())?;
+                        .map_err(internal_error)?;
                     let address = params[0]
                         .clone()
                         .into_address()
@@ -290,16 +343,13 @@ impl CallsHelper {
                         .account_schema()
                         .get_account_balance_for_block(address, block, token.id)
                         .await
-                        .map_err(|_| Error::internal_error())?;
+                        .map_err(internal_error)?;
                     encode(&[AbiToken::Uint(u256_from_biguint(balance))])
                 }
                 _ => unreachable!(),
             }
         };
-        transaction
-            .commit()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        transaction.commit().await.map_err(internal_error)?;
         Ok(result)
     }
 
@@ -315,7 +365,7 @@ impl CallsHelper {
             .tokens
             .get_nft_by_id(storage, TokenId(token_id.as_u32()))
             .await
-            .map_err(|_| Error::internal_error())?;
+            .map_err(internal_error)?;
         Ok(nft)
     }
 
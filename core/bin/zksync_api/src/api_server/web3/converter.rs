@@ -1,20 +1,85 @@
 // Built-in uses
 use std::convert::TryInto;
 // External uses
-use jsonrpc_core::{Error, Result};
+use jsonrpc_core::Result;
 use num::BigUint;
 // Workspace uses
-use zksync_storage::StorageProcessor;
+use zksync_storage::{chain::operations_ext::records::Web3TxData, StorageProcessor};
+use zksync_types::{TokenId, ZkSyncOp};
 // Local uses
-use super::types::{BlockNumber, Bytes, CommonLogData, Log, Transaction, TxData, H160, H256, U256};
+use super::{
+    error::{internal_error, invalid_param},
+    types::{BlockNumber, Bytes, CommonLogData, Log, Transaction, TxData, H160, H256, U256, U64},
+};
 
 pub fn u256_from_biguint(number: BigUint) -> U256 {
     U256::from_dec_str(&number.to_string()).unwrap()
 }
 
+/// Returns the `value` of the web3 transaction representing the executed operation.
+///
+/// `value` is denominated in ETH, so it's only set for the operations moving ETH to L1:
+/// withdrawals and forced exits. Amounts of other operations and tokens are described
+/// by the synthesized ERC20 `Transfer` logs.
+pub fn tx_value(op: &ZkSyncOp) -> U256 {
+    match op {
+        ZkSyncOp::Withdraw(op) if op.tx.token == TokenId(0) => {
+            u256_from_biguint(op.tx.amount.clone())
+        }
+        ZkSyncOp::ForcedExit(op) if op.tx.token == TokenId(0) => op
+            .withdraw_amount
+            .clone()
+            .map(|amount| u256_from_biguint(amount.0))
+            .unwrap_or_default(),
+        _ => U256::zero(),
+    }
+}
+
+/// Converts the transaction loaded from the storage into `TxData`. The stored operation
+/// is parsed to compute the `value` of the transaction.
+pub fn tx_data_from_web3(tx: Web3TxData) -> Result<TxData> {
+    let value = match tx.operation {
+        Some(operation) => serde_json::from_value::<Option<ZkSyncOp>>(operation)
+            .map_err(internal_error)?
+            .map(|op| tx_value(&op))
+            .unwrap_or_default(),
+        None => U256::zero(),
+    };
+    Ok(TxData {
+        block_hash: H256::from_slice(&tx.block_hash),
+        block_number: tx.block_number as u32,
+        block_index: tx.block_index.map(|i| i as u32),
+        from: H160::from_slice(&tx.from_account),
+        to: tx.to_account.map(|to| H160::from_slice(&to)),
+        nonce: tx.nonce as u32,
+        tx_hash: H256::from_slice(&tx.tx_hash),
+        value,
+    })
+}
+
+/// Converts the block number passed as the `param` parameter into the storage block number.
+/// Returns an error instead of truncating numbers that don't fit into `u32`.
+pub fn storage_block_number(number: U64, param: &str) -> Result<zksync_types::BlockNumber> {
+    number
+        .as_u64()
+        .try_into()
+        .map(zksync_types::BlockNumber)
+        .map_err(|_| {
+            invalid_param(
+                param,
+                format!(
+                    "block number {} exceeds the max block number {}",
+                    number,
+                    u32::MAX
+                ),
+            )
+        })
+}
+
 pub async fn resolve_block_number(
     storage: &mut StorageProcessor<'_>,
     number: Option<BlockNumber>,
+    param: &str,
 ) -> Result<Option<zksync_types::BlockNumber>> {
     let number = match number {
         Some(number) => number,
@@ -25,7 +90,7 @@ pub async fn resolve_block_number(
                     .block_schema()
                     .get_last_verified_confirmed_block()
                     .await
-                    .map_err(|_| Error::internal_error())?,
+                    .map_err(internal_error)?,
             ));
         }
     };
@@ -37,32 +102,31 @@ pub async fn resolve_block_number(
             .block_schema()
             .get_last_committed_confirmed_block()
             .await
-            .map_err(|_| Error::internal_error())?,
-        BlockNumber::Finalized | BlockNumber::Latest => storage
+            .map_err(internal_error)?,
+        BlockNumber::Finalized | BlockNumber::Safe | BlockNumber::Latest => storage
             .chain()
             .block_schema()
             .get_last_verified_confirmed_block()
             .await
-            .map_err(|_| Error::internal_error())?,
+            .map_err(internal_error)?,
         BlockNumber::Pending => storage
             .chain()
             .block_schema()
             .get_last_saved_block()
             .await
-            .map_err(|_| Error::internal_error())?,
+            .map_err(internal_error)?,
         BlockNumber::Number(number) => {
+            let number = storage_block_number(number, param)?;
             let last_saved_block = storage
                 .chain()
                 .block_schema()
                 .get_last_saved_block()
                 .await
-                .map_err(|_| Error::internal_error())?;
-            if number.as_u64() > last_saved_block.0 as u64 {
+                .map_err(internal_error)?;
+            if number > last_saved_block {
                 return Ok(None);
             }
-            // Unwrap can be safely used because `number` is not greater than `last_saved_block`
-            // which is `u32` variable.
-            zksync_types::BlockNumber(number.as_u64().try_into().unwrap())
+            number
         }
     };
     Ok(Some(number))
@@ -77,7 +141,7 @@ pub fn transaction_from_tx_data(tx: TxData) -> Transaction {
         transaction_index: tx.block_index.map(Into::into),
         from: Some(tx.from),
         to: tx.to,
-        value: 0.into(),
+        value: tx.value,
         gas_price: Some(0.into()),
         gas: 0.into(),
         input: Vec::new().into(),
@@ -0,0 +1,34 @@
+// Built-in uses
+use std::fmt;
+// External uses
+use jsonrpc_core::{Error, ErrorCode};
+use serde_json::Value;
+// Workspace uses
+// Local uses
+
+/// Creates an internal error that keeps the description of the underlying error as its data.
+pub fn internal_error(err: impl fmt::Display) -> Error {
+    Error {
+        data: Some(Value::String(err.to_string())),
+        ..Error::internal_error()
+    }
+}
+
+/// Creates an "invalid params" error that names the offending parameter.
+pub fn invalid_param(name: &str, err: impl fmt::Display) -> Error {
+    Error::invalid_params(format!("Invalid `{}`: {}", name, err))
+}
+
+/// Prepares the error to be sent to the client. The details of internal errors are appended
+/// to the message if they are revealed by the config, and removed otherwise.
+pub fn sanitize_error(mut err: Error, reveal_internal_errors: bool) -> Error {
+    if err.code != ErrorCode::InternalError {
+        return err;
+    }
+    if !reveal_internal_errors {
+        err.data = None;
+    } else if let Some(Value::String(details)) = &err.data {
+        err.message = format!("{}: {}", err.message, details);
+    }
+    err
+}
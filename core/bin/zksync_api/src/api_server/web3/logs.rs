@@ -14,10 +14,39 @@ use zksync_types::{Nonce, Token, TokenId, TokenKind, ZkSyncOp, NFT};
 // Local uses
 use super::{
     converter::{log, u256_from_biguint},
+    error::internal_error,
     types::{Bytes, CommonLogData, Event, Log, H160, H256, U256},
     NFT_FACTORY_ADDRESS, ZKSYNC_PROXY_ADDRESS,
 };
 
+/// Address and topic constraints of an `eth_getLogs` request applied to synthesized logs.
+///
+/// Logs are matched by the contract address they were synthesized with rather than by the
+/// internal token id, so all token ids that share one L1 address (e.g. a migrated token)
+/// contribute logs to a query filtered by that address.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogsFilter {
+    addresses: Vec<H160>,
+    topics: Vec<H256>,
+}
+
+impl LogsFilter {
+    /// Empty `addresses` or `topics` don't constrain the logs, e.g. `topics: []` matches all topics.
+    pub fn new(addresses: Vec<H160>, topics: Vec<H256>) -> Self {
+        Self { addresses, topics }
+    }
+
+    pub fn matches(&self, log: &Log) -> bool {
+        if !self.topics.is_empty() && !self.topics.contains(&log.topics[0]) {
+            return false;
+        }
+        if !self.addresses.is_empty() && !self.addresses.contains(&log.address) {
+            return false;
+        }
+        true
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct LogsHelper {
     topic_by_event: HashMap<Event, H256>,
@@ -151,7 +180,7 @@ impl LogsHelper {
                     .account_schema()
                     .account_address_by_id(op.tx.initiator_account_id)
                     .await
-                    .map_err(|_| Error::internal_error())?
+                    .map_err(internal_error)?
                     .ok_or_else(Error::internal_error)?;
                 let data = Self::zksync_forced_exit_data(
                     initiator,
@@ -217,14 +246,14 @@ impl LogsHelper {
                     .account_schema()
                     .account_address_by_id(op.accounts.0)
                     .await
-                    .map_err(|_| Error::internal_error())?
+                    .map_err(internal_error)?
                     .ok_or_else(Error::internal_error)?;
                 let account2 = storage
                     .chain()
                     .account_schema()
                     .account_address_by_id(op.accounts.1)
                     .await
-                    .map_err(|_| Error::internal_error())?
+                    .map_err(internal_error)?
                     .ok_or_else(Error::internal_error)?;
                 let data = Self::zksync_swap_data(
                     op.tx.submitter_address,
@@ -259,7 +288,7 @@ impl LogsHelper {
                     .account_schema()
                     .account_address_by_id(op.priority_op.account_id)
                     .await
-                    .map_err(|_| Error::internal_error())?
+                    .map_err(internal_error)?
                     .ok_or_else(Error::internal_error)?;
                 let data =
                     Self::zksync_full_exit_data(account, token.address, u256_from_biguint(amount));
@@ -310,7 +339,7 @@ impl LogsHelper {
                     .account_schema()
                     .account_address_by_id(op.tx.initiator_account_id)
                     .await
-                    .map_err(|_| Error::internal_error())?
+                    .map_err(internal_error)?
                     .ok_or_else(Error::internal_error)?;
                 let amount = op.withdraw_amount.unwrap_or_default().0;
                 result.push((token.clone(), op.tx.target, H160::zero(), amount));
@@ -348,14 +377,14 @@ impl LogsHelper {
                     .account_schema()
                     .account_address_by_id(op.tx.orders.0.account_id)
                     .await
-                    .map_err(|_| Error::internal_error())?
+                    .map_err(internal_error)?
                     .ok_or_else(Error::internal_error)?;
                 let from2 = storage
                     .chain()
                     .account_schema()
                     .account_address_by_id(op.tx.orders.1.account_id)
                     .await
-                    .map_err(|_| Error::internal_error())?
+                    .map_err(internal_error)?
                     .ok_or_else(Error::internal_error)?;
                 result.push((
                     token1,
@@ -387,7 +416,7 @@ impl LogsHelper {
                     .account_schema()
                     .account_address_by_id(op.priority_op.account_id)
                     .await
-                    .map_err(|_| Error::internal_error())?
+                    .map_err(internal_error)?
                     .ok_or_else(Error::internal_error)?;
                 result.push((
                     token,
@@ -423,6 +452,16 @@ impl LogsHelper {
         Ok(logs)
     }
 
+    /// Returns the registered tokens used for the logs synthesis, NFTs are not included.
+    pub async fn tokens(&self, storage: &mut StorageProcessor<'_>) -> Result<Vec<Token>> {
+        let tokens = self
+            .tokens
+            .get_cached_tokens(storage)
+            .await
+            .map_err(internal_error)?;
+        Ok(tokens.into_iter().filter(|token| !token.is_nft).collect())
+    }
+
     async fn get_token_by_id(
         &self,
         storage: &mut StorageProcessor<'_>,
@@ -431,7 +470,7 @@ impl LogsHelper {
         self.tokens
             .get_token(storage, id)
             .await
-            .map_err(|_| Error::internal_error())?
+            .map_err(internal_error)?
             .ok_or_else(Error::internal_error)
     }
 
@@ -439,7 +478,7 @@ impl LogsHelper {
         self.tokens
             .get_nft_by_id(storage, id)
             .await
-            .map_err(|_| Error::internal_error())?
+            .map_err(internal_error)?
             .ok_or_else(Error::internal_error)
     }
 
@@ -454,11 +493,11 @@ impl LogsHelper {
             .state_schema()
             .get_mint_nft_update_by_creator_and_nonce(creator_address, nonce)
             .await
-            .map_err(|_| Error::internal_error())?
+            .map_err(internal_error)?
             .ok_or_else(Error::internal_error)
     }
 
-    fn erc_transfer(
+    pub(super) fn erc_transfer(
         &self,
         token: Token,
         from: H160,
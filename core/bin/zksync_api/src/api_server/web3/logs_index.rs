@@ -0,0 +1,116 @@
+// Built-in uses
+use std::collections::VecDeque;
+// External uses
+// Workspace uses
+use zksync_types::BlockNumber;
+// Local uses
+use super::{
+    logs::LogsFilter,
+    types::{Log, H256},
+};
+
+#[derive(Debug)]
+struct IndexedBlock {
+    number: BlockNumber,
+    hash: H256,
+    logs: Vec<Log>,
+}
+
+/// In-memory index of the logs synthesized for a contiguous range of the latest blocks.
+///
+/// Blocks are appended as they are saved to storage (see `Web3RpcApp::update_logs_index`),
+/// so the logs of the latest blocks are synthesized only once. Reverted blocks are removed with
+/// `truncate`.
+#[derive(Debug)]
+pub struct LogsIndex {
+    blocks: VecDeque<IndexedBlock>,
+    max_blocks: usize,
+}
+
+impl LogsIndex {
+    pub fn new(max_blocks: usize) -> Self {
+        Self {
+            blocks: VecDeque::new(),
+            max_blocks,
+        }
+    }
+
+    /// Returns `false` if the index is disabled by the configuration.
+    pub fn is_enabled(&self) -> bool {
+        self.max_blocks > 0
+    }
+
+    pub fn first_block(&self) -> Option<BlockNumber> {
+        self.blocks.front().map(|block| block.number)
+    }
+
+    /// Returns the number and the hash of the last indexed block.
+    pub fn last_block(&self) -> Option<(BlockNumber, H256)> {
+        self.blocks.back().map(|block| (block.number, block.hash))
+    }
+
+    /// Returns the first block that should be appended to the index, given the last block in storage.
+    ///
+    /// If the index is empty, it's started from the oldest block that fits into it.
+    pub fn next_block(&self, last_block: BlockNumber) -> BlockNumber {
+        match self.last_block() {
+            Some((block_number, _)) => block_number + 1,
+            None => BlockNumber(
+                last_block
+                    .0
+                    .saturating_sub(self.max_blocks as u32)
+                    .saturating_add(1)
+                    .max(1),
+            ),
+        }
+    }
+
+    /// Appends the block to the end of the index, evicting the oldest blocks if the index is full.
+    ///
+    /// Blocks that are already indexed are ignored. If there is a gap between the last indexed
+    /// block and the new one, the index is restarted from the new block.
+    pub fn append_block(&mut self, number: BlockNumber, hash: H256, logs: Vec<Log>) {
+        if !self.is_enabled() {
+            return;
+        }
+        if let Some((last_block, _)) = self.last_block() {
+            if number <= last_block {
+                return;
+            }
+            if number != last_block + 1 {
+                self.clear();
+            }
+        }
+        self.blocks.push_back(IndexedBlock { number, hash, logs });
+        while self.blocks.len() > self.max_blocks {
+            self.blocks.pop_front();
+        }
+    }
+
+    /// Removes all the blocks after `last_block` from the index.
+    pub fn truncate(&mut self, last_block: BlockNumber) {
+        while matches!(self.blocks.back(), Some(block) if block.number > last_block) {
+            self.blocks.pop_back();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.blocks.clear();
+    }
+
+    /// Returns the logs of the indexed blocks from the range that match the filter.
+    pub fn logs(
+        &self,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+        filter: &LogsFilter,
+    ) -> Vec<Log> {
+        self.blocks
+            .iter()
+            .filter(|block| block.number >= from_block && block.number <= to_block)
+            .flat_map(|block| block.logs.iter())
+            .filter(|log| filter.matches(log))
+            .cloned()
+            .collect()
+    }
+}
@@ -1,14 +1,23 @@
 // Built-in uses
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 // External uses
 
-use jsonrpc_core::{Error, IoHandler, MetaIoHandler, Metadata, Middleware, Result};
+use jsonrpc_core::{MetaIoHandler, Metadata, Middleware, Result};
 use jsonrpc_http_server::ServerBuilder;
 // Workspace uses
 
 use zksync_storage::{ConnectionPool, StorageProcessor};
 use zksync_utils::panic_notify::{spawn_panic_handler, ThreadPanicNotify};
 // Local uses
-use self::{calls::CallsHelper, logs::LogsHelper, rpc_trait::Web3Rpc};
+use self::{
+    calls::CallsHelper,
+    error::{internal_error, sanitize_error},
+    logs::LogsHelper,
+    logs_index::LogsIndex,
+    rpc_trait::Web3Rpc,
+    version_middleware::JsonRpcVersionMiddleware,
+};
 
 use tokio::task::JoinHandle;
 use zksync_config::configs::api::{TokenConfig, Web3Config};
@@ -16,12 +25,15 @@ use zksync_types::ChainId;
 
 mod calls;
 mod converter;
+mod error;
 mod logs;
+mod logs_index;
 mod rpc_impl;
 mod rpc_trait;
 #[cfg(test)]
 mod tests;
 mod types;
+mod version_middleware;
 
 pub const ZKSYNC_PROXY_ADDRESS: &str = "1000000000000000000000000000000000000000";
 pub const NFT_FACTORY_ADDRESS: &str = "2000000000000000000000000000000000000000";
@@ -30,8 +42,16 @@ pub const NFT_FACTORY_ADDRESS: &str = "2000000000000000000000000000000000000000"
 pub struct Web3RpcApp {
     connection_pool: ConnectionPool,
     logs_helper: LogsHelper,
+    logs_index: Arc<RwLock<LogsIndex>>,
     calls_helper: CallsHelper,
     max_block_range: u32,
+    max_fee_history_block_count: u64,
+    max_fee_history_reward_percentiles: u64,
+    pending_includes_unconfirmed_blocks: bool,
+    latest_includes_unfinalized_blocks: bool,
+    omit_total_difficulty: bool,
+    reveal_internal_errors: bool,
+    stuck_tx_threshold: Duration,
     chain_id: ChainId,
 }
 
@@ -44,8 +64,18 @@ impl Web3RpcApp {
         Web3RpcApp {
             connection_pool,
             logs_helper: LogsHelper::new(token_config.invalidate_token_cache_period()),
+            logs_index: Arc::new(RwLock::new(LogsIndex::new(
+                config.logs_index_max_blocks as usize,
+            ))),
             calls_helper: CallsHelper::new(token_config.invalidate_token_cache_period()),
             max_block_range: config.max_block_range,
+            max_fee_history_block_count: config.max_fee_history_block_count,
+            max_fee_history_reward_percentiles: config.max_fee_history_reward_percentiles,
+            pending_includes_unconfirmed_blocks: config.pending_includes_unconfirmed_blocks,
+            latest_includes_unfinalized_blocks: config.latest_includes_unfinalized_blocks,
+            omit_total_difficulty: config.omit_total_difficulty,
+            reveal_internal_errors: config.reveal_internal_errors,
+            stuck_tx_threshold: config.stuck_tx_threshold(),
             chain_id: ChainId(config.chain_id),
         }
     }
@@ -54,11 +84,28 @@ impl Web3RpcApp {
         io.extend_with(self.to_delegate())
     }
 
+    /// Keeps the logs index in sync with the saved blocks, polling storage with the given interval.
+    pub async fn keep_logs_index_updated(self, interval: Duration) {
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            interval.tick().await;
+
+            let result = async {
+                let mut storage = self.access_storage().await?;
+                self.update_logs_index(&mut storage).await
+            };
+            if let Err(err) = result.await {
+                let err = sanitize_error(err, true);
+                vlog::warn!("Unable to update the web3 logs index: {}", err.message);
+            }
+        }
+    }
+
     async fn access_storage(&self) -> Result<StorageProcessor<'_>> {
         self.connection_pool
             .access_storage()
             .await
-            .map_err(|_| Error::internal_error())
+            .map_err(internal_error)
     }
 }
 
@@ -66,16 +113,27 @@ pub fn start_rpc_server(
     connection_pool: ConnectionPool,
     web3_config: &Web3Config,
     token_config: &TokenConfig,
-) -> JoinHandle<()> {
+) -> anyhow::Result<JoinHandle<()>> {
+    web3_config
+        .validate()
+        .map_err(|err| anyhow::anyhow!("Invalid web3 configuration: {}", err))?;
     let addr = web3_config.bind_addr();
 
     let rpc_app = Web3RpcApp::new(connection_pool, web3_config, token_config);
     let (handler, panic_sender) = spawn_panic_handler();
 
+    if rpc_app.logs_index.read().unwrap().is_enabled() {
+        tokio::spawn(
+            rpc_app
+                .clone()
+                .keep_logs_index_updated(web3_config.logs_index_update_interval()),
+        );
+    }
+
     std::thread::spawn(move || {
         let _panic_sentinel = ThreadPanicNotify(panic_sender);
 
-        let mut io = IoHandler::new();
+        let mut io = MetaIoHandler::<(), _>::with_middleware(JsonRpcVersionMiddleware);
         rpc_app.extend(&mut io);
 
         let server = ServerBuilder::new(io)
@@ -84,5 +142,5 @@ pub fn start_rpc_server(
             .unwrap();
         server.wait();
     });
-    handler
+    Ok(handler)
 }
@@ -1,7 +1,9 @@
 // Built-in uses
 use ethabi::Address;
+use std::collections::HashMap;
 use std::time::Instant;
 // External uses
+use chrono::Utc;
 use jsonrpc_core::{Error, Result};
 // Workspace uses
 use zksync_crypto::convert::FeConvert;
@@ -13,10 +15,16 @@ use zksync_types::withdrawals::WithdrawalPendingEvent;
 use zksync_types::{ExecutedOperations, TokenId, ZkSyncOp};
 // Local uses
 use super::{
-    converter::{resolve_block_number, transaction_from_tx_data, u256_from_biguint},
+    converter::{
+        resolve_block_number, transaction_from_tx_data, tx_data_from_web3, tx_value,
+        u256_from_biguint,
+    },
+    error::{internal_error, invalid_param},
+    logs::LogsFilter,
     types::{
-        BlockInfo, BlockNumber, Bytes, CallRequest, CommonLogData, Filter, Log, Transaction,
-        TransactionReceipt, TxData, H160, H2048, H256, U256, U64,
+        BlockInfo, BlockNumber, Bytes, CallRequest, CommonLogData, FeeHistory, Filter, Log,
+        LogsWithMeta, TokenInfo, Transaction, TransactionDetails, TransactionReceipt, TxData, H160,
+        H2048, H256, U256, U64,
     },
     Web3RpcApp,
 };
@@ -25,12 +33,11 @@ impl Web3RpcApp {
     pub async fn _impl_block_number(self) -> Result<U64> {
         let start = Instant::now();
         let mut storage = self.access_storage().await?;
-        let block_number = storage
-            .chain()
-            .block_schema()
-            .get_last_verified_confirmed_block()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        // The `latest` block always exists.
+        let block_number = self
+            .resolve_block_number(&mut storage, Some(BlockNumber::Latest), "latest")
+            .await?
+            .ok_or_else(Error::internal_error)?;
 
         metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "block_number");
         Ok(U64::from(block_number.0))
@@ -43,22 +50,85 @@ impl Web3RpcApp {
     ) -> Result<U256> {
         let start = Instant::now();
         let mut storage = self.access_storage().await?;
-        let mut transaction = storage
-            .start_transaction()
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
+        let result = self
+            .balance(&mut transaction, address, TokenId(0), block)
+            .await?;
+        transaction.commit().await.map_err(internal_error)?;
+
+        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_balance");
+        Ok(result)
+    }
+
+    pub async fn _impl_get_token_balance(
+        self,
+        address: H160,
+        token: H160,
+        block: Option<BlockNumber>,
+    ) -> Result<U256> {
+        let start = Instant::now();
+        let mut storage = self.access_storage().await?;
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
+
+        let token = self
+            .calls_helper
+            .token_by_address(&mut transaction, token)
+            .await?
+            .filter(|token| !token.is_nft)
+            .ok_or_else(|| Error::invalid_params("Token with such address doesn't exist"))?;
+        let result = self
+            .balance(&mut transaction, address, token.id, block)
+            .await?;
+        transaction.commit().await.map_err(internal_error)?;
+
+        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_token_balance");
+        Ok(result)
+    }
+
+    /// Returns the balance of the account at the block, zero if the account has never held the token.
+    async fn balance(
+        &self,
+        storage: &mut StorageProcessor<'_>,
+        address: H160,
+        token_id: TokenId,
+        block: Option<BlockNumber>,
+    ) -> Result<U256> {
+        let block_number = self
+            .resolve_block_number(storage, block, "block")
+            .await?
+            .ok_or_else(|| Error::invalid_params("Block with such number doesn't exist yet"))?;
+        let balance = storage
+            .chain()
+            .account_schema()
+            .get_account_balance_for_block(address, block_number, token_id)
             .await
-            .map_err(|_| Error::internal_error())?;
-        let block_number = resolve_block_number(&mut transaction, block)
+            .map_err(internal_error)?;
+        Ok(u256_from_biguint(balance))
+    }
+
+    pub async fn _impl_get_transaction_count(
+        self,
+        address: H160,
+        block: Option<BlockNumber>,
+    ) -> Result<U256> {
+        let start = Instant::now();
+        let mut storage = self.access_storage().await?;
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
+
+        let block_number = self
+            .resolve_block_number(&mut transaction, block, "block")
             .await?
             .ok_or_else(|| Error::invalid_params("Block with such number doesn't exist yet"))?;
-        let balance = transaction
+        let nonce = transaction
             .chain()
             .account_schema()
-            .get_account_balance_for_block(address, block_number, TokenId(0))
+            .get_account_nonce_for_block(address, block_number)
             .await
-            .map_err(|_| Error::internal_error())?;
-        let result = u256_from_biguint(balance);
-        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_balance");
-        Ok(result)
+            .map_err(internal_error)?;
+        transaction.commit().await.map_err(internal_error)?;
+
+        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_transaction_count");
+        Ok(U256::from(*nonce))
     }
 
     pub async fn _impl_get_block_transaction_count_by_hash(
@@ -67,27 +137,21 @@ impl Web3RpcApp {
     ) -> Result<Option<U256>> {
         let start = Instant::now();
         let mut storage = self.access_storage().await?;
-        let mut transaction = storage
-            .start_transaction()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
 
         let block_number = transaction
             .chain()
             .block_schema()
             .get_block_number_by_hash(hash.as_bytes())
             .await
-            .map_err(|_| Error::internal_error())?;
+            .map_err(internal_error)?;
         let result = match block_number {
             Some(block_number) => {
                 Some(Self::block_transaction_count(&mut transaction, block_number).await?)
             }
             None => None,
         };
-        transaction
-            .commit()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        transaction.commit().await.map_err(internal_error)?;
 
         metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_block_transaction_count_by_hash");
         Ok(result)
@@ -99,22 +163,18 @@ impl Web3RpcApp {
     ) -> Result<Option<U256>> {
         let start = Instant::now();
         let mut storage = self.access_storage().await?;
-        let mut transaction = storage
-            .start_transaction()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
 
-        let block_number = resolve_block_number(&mut transaction, block).await?;
+        let block_number = self
+            .resolve_block_number(&mut transaction, block, "block")
+            .await?;
         let result = match block_number {
             Some(block_number) => {
                 Some(Self::block_transaction_count(&mut transaction, block_number).await?)
             }
             None => None,
         };
-        transaction
-            .commit()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        transaction.commit().await.map_err(internal_error)?;
 
         metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_block_transaction_count_by_number");
         Ok(result)
@@ -129,13 +189,61 @@ impl Web3RpcApp {
             .operations_ext_schema()
             .tx_data_for_web3(hash.as_ref())
             .await
-            .map_err(|_| Error::internal_error())?;
-        let result = tx.map(|tx| transaction_from_tx_data(tx.into()));
+            .map_err(internal_error)?;
+        let result = tx
+            .map(tx_data_from_web3)
+            .transpose()?
+            .map(transaction_from_tx_data);
 
         metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_transaction_by_hash");
         Ok(result)
     }
 
+    pub async fn _impl_get_transaction_details(
+        self,
+        hash: H256,
+    ) -> Result<Option<TransactionDetails>> {
+        let start = Instant::now();
+        let mut storage = self.access_storage().await?;
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
+
+        let tx = transaction
+            .chain()
+            .operations_ext_schema()
+            .tx_data_for_web3(hash.as_ref())
+            .await
+            .map_err(internal_error)?;
+        let result = match tx {
+            Some(tx) => Some(TransactionDetails {
+                hash,
+                pending: false,
+                received_at: None,
+                may_be_stuck: false,
+                transaction: Some(transaction_from_tx_data(tx_data_from_web3(tx)?)),
+            }),
+            None => transaction
+                .chain()
+                .mempool_schema()
+                .get_tx(hash.as_bytes())
+                .await
+                .map_err(internal_error)?
+                .map(|tx| {
+                    let pending_for = (Utc::now() - tx.created_at).to_std().unwrap_or_default();
+                    TransactionDetails {
+                        hash,
+                        pending: true,
+                        received_at: Some(tx.created_at),
+                        may_be_stuck: pending_for > self.stuck_tx_threshold,
+                        transaction: None,
+                    }
+                }),
+        };
+        transaction.commit().await.map_err(internal_error)?;
+
+        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_transaction_details");
+        Ok(result)
+    }
+
     pub async fn _impl_get_block_by_number(
         self,
         block_number: Option<BlockNumber>,
@@ -143,25 +251,21 @@ impl Web3RpcApp {
     ) -> Result<Option<BlockInfo>> {
         let start = Instant::now();
         let mut storage = self.access_storage().await?;
-        let mut transaction = storage
-            .start_transaction()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
 
-        let block_number = resolve_block_number(&mut transaction, block_number).await?;
+        let block_number = self
+            .resolve_block_number(&mut transaction, block_number, "block")
+            .await?;
         let result = match block_number {
             Some(block_number) => {
                 Some(Self::block_by_number(&mut transaction, block_number, include_txs).await?)
             }
             None => None,
         };
-        transaction
-            .commit()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        transaction.commit().await.map_err(internal_error)?;
 
         metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_block_by_number");
-        Ok(result)
+        Ok(result.map(|block| self.block_response(block)))
     }
 
     pub async fn _impl_get_block_by_hash(
@@ -171,30 +275,33 @@ impl Web3RpcApp {
     ) -> Result<Option<BlockInfo>> {
         let start = Instant::now();
         let mut storage = self.access_storage().await?;
-        let mut transaction = storage
-            .start_transaction()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
 
         let block_number = transaction
             .chain()
             .block_schema()
             .get_block_number_by_hash(hash.as_bytes())
             .await
-            .map_err(|_| Error::internal_error())?;
+            .map_err(internal_error)?;
         let result = match block_number {
             Some(block_number) => {
                 Some(Self::block_by_number(&mut transaction, block_number, include_txs).await?)
             }
             None => None,
         };
-        transaction
-            .commit()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        transaction.commit().await.map_err(internal_error)?;
 
         metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_block_by_hash");
-        Ok(result)
+        Ok(result.map(|block| self.block_response(block)))
+    }
+
+    /// Applies the configured block format to the block returned by the API.
+    fn block_response(&self, block: BlockInfo) -> BlockInfo {
+        if self.omit_total_difficulty {
+            block.without_total_difficulty()
+        } else {
+            block
+        }
     }
 
     pub async fn _impl_get_transaction_receipt(
@@ -203,20 +310,29 @@ impl Web3RpcApp {
     ) -> Result<Option<TransactionReceipt>> {
         let start = Instant::now();
         let mut storage = self.access_storage().await?;
+        let result = self.receipt_by_hash(&mut storage, hash).await?;
+
+        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_transaction_receipt");
+        Ok(result)
+    }
+
+    pub async fn _impl_get_transaction_logs(self, hash: H256) -> Result<Option<Vec<Log>>> {
+        let start = Instant::now();
+        let mut storage = self.access_storage().await?;
 
         let tx = storage
             .chain()
             .operations_ext_schema()
             .web3_receipt_by_hash(hash.as_ref())
             .await
-            .map_err(|_| Error::internal_error())?;
+            .map_err(internal_error)?;
         let result = if let Some(tx) = tx {
-            Some(self.tx_receipt(&mut storage, tx).await?)
+            Some(self.logs_from_receipt(&mut storage, tx).await?)
         } else {
             None
         };
 
-        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_transaction_receipt");
+        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_transaction_logs");
         Ok(result)
     }
 
@@ -224,21 +340,80 @@ impl Web3RpcApp {
         let start = Instant::now();
 
         let mut storage = self.access_storage().await?;
-        let mut transaction = storage
-            .start_transaction()
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
+
+        let (logs, _) = self.logs_by_filter(&mut transaction, filter).await?;
+
+        transaction.commit().await.map_err(internal_error)?;
+
+        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_logs");
+        Ok(logs)
+    }
+
+    pub async fn _impl_get_logs_with_meta(self, filter: Filter) -> Result<LogsWithMeta> {
+        let start = Instant::now();
+
+        let mut storage = self.access_storage().await?;
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
+
+        let (logs, to_block) = self.logs_by_filter(&mut transaction, filter).await?;
+        // Logs and the finalized head are loaded within the same DB transaction,
+        // so the head is consistent with the returned logs.
+        let last_finalized_block = transaction
+            .chain()
+            .block_schema()
+            .get_last_verified_confirmed_block()
             .await
-            .map_err(|_| Error::internal_error())?;
+            .map_err(internal_error)?;
 
-        let from_block = resolve_block_number(&mut transaction, filter.from_block).await?;
-        let to_block = resolve_block_number(&mut transaction, filter.to_block).await?;
+        transaction.commit().await.map_err(internal_error)?;
 
-        let (from_block, to_block) = match (from_block, to_block) {
-            (Some(from_block), Some(to_block)) => (from_block, to_block),
-            _ => {
+        let result = LogsWithMeta {
+            logs,
+            finalized_block: U64::from(to_block.min(last_finalized_block).0),
+        };
+        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_logs_with_meta");
+        Ok(result)
+    }
+
+    /// Returns the logs matching the filter along with the last block of the requested range.
+    ///
+    /// Like in geth, an unknown `blockHash` results in an error. Reverted blocks are removed
+    /// from storage, so hashes of the reverted blocks are unknown as well.
+    pub(crate) async fn logs_by_filter(
+        &self,
+        storage: &mut StorageProcessor<'_>,
+        filter: Filter,
+    ) -> Result<(Vec<Log>, zksync_types::BlockNumber)> {
+        let (from_block, to_block) = if let Some(block_hash) = filter.block_hash {
+            if filter.from_block.is_some() || filter.to_block.is_some() {
                 return Err(Error::invalid_params(
-                    "Block with such number doesn't exist yet",
+                    "`blockHash` can't be combined with `fromBlock` or `toBlock`",
                 ));
             }
+            let block_number = storage
+                .chain()
+                .block_schema()
+                .get_block_number_by_hash(block_hash.as_bytes())
+                .await
+                .map_err(internal_error)?
+                .ok_or_else(|| invalid_param("blockHash", "unknown block"))?;
+            (block_number, block_number)
+        } else {
+            let from_block = self
+                .resolve_block_number(storage, filter.from_block, "fromBlock")
+                .await?;
+            let to_block = self
+                .resolve_block_number(storage, filter.to_block, "toBlock")
+                .await?;
+            match (from_block, to_block) {
+                (Some(from_block), Some(to_block)) => (from_block, to_block),
+                _ => {
+                    return Err(Error::invalid_params(
+                        "Block with such number doesn't exist yet",
+                    ));
+                }
+            }
         };
 
         if from_block > to_block {
@@ -261,7 +436,7 @@ impl Web3RpcApp {
                 .enumerate()
                 .any(|(i, topic)| i > 0 && topic.is_some());
             if has_not_first {
-                return Ok(Vec::new());
+                return Ok((Vec::new(), to_block));
             } else if topics.is_empty() {
                 Vec::new()
             } else {
@@ -270,38 +445,181 @@ impl Web3RpcApp {
         } else {
             Vec::new()
         };
-        let addresses = filter.address.map(|a| a.0).unwrap_or_default();
+        let addresses = match filter.address {
+            // An empty list of addresses matches no logs, while omitted `address` matches any.
+            Some(addresses) if addresses.0.is_empty() => return Ok((Vec::new(), to_block)),
+            Some(addresses) => addresses.0,
+            None => Vec::new(),
+        };
+        let filter = LogsFilter::new(addresses, topics);
+
+        if !self.logs_index.read().unwrap().is_enabled() {
+            let result = self
+                .filtered_logs(storage, from_block, to_block, &filter)
+                .await?;
+            return Ok((result, to_block));
+        }
+
+        // The index is updated periodically, so it may still contain the blocks replaced
+        // by a revert. They are dropped before the index is read, so that it only serves
+        // the blocks that match the storage.
+        // The index state is captured along with the indexed logs,
+        // so concurrent updates of the index can't affect the response.
+        let last_saved_block = storage
+            .chain()
+            .block_schema()
+            .get_last_saved_block()
+            .await
+            .map_err(internal_error)?;
+        self.drop_reverted_logs(storage, last_saved_block).await?;
+        let (indexed_range, indexed_logs) = {
+            let index = self.logs_index.read().unwrap();
+            let range = index
+                .first_block()
+                .zip(index.last_block().map(|(block_number, _)| block_number));
+            let logs = range
+                .map(|(first, last)| index.logs(from_block.max(first), to_block.min(last), &filter))
+                .unwrap_or_default();
+            (range, logs)
+        };
+
         let mut result = Vec::new();
+        let tail_start = match indexed_range {
+            Some((first, last)) => {
+                // Blocks before the index are not added to it, so they're loaded from storage.
+                if from_block < first {
+                    let head = self
+                        .filtered_logs(storage, from_block, to_block.min(first - 1), &filter)
+                        .await?;
+                    result.extend(head);
+                }
+                result.extend(indexed_logs);
+                from_block.max(last + 1)
+            }
+            None => from_block,
+        };
+        // Blocks that are not indexed yet are loaded from storage, the index is updated
+        // by `update_logs_index` as new blocks are saved.
+        if tail_start <= to_block {
+            let tail = self
+                .filtered_logs(storage, tail_start, to_block, &filter)
+                .await?;
+            result.extend(tail);
+        }
+
+        Ok((result, to_block))
+    }
 
-        let receipts = transaction
+    /// Loads the logs of the blocks from the range that match the filter.
+    async fn filtered_logs(
+        &self,
+        storage: &mut StorageProcessor<'_>,
+        from_block: zksync_types::BlockNumber,
+        to_block: zksync_types::BlockNumber,
+        filter: &LogsFilter,
+    ) -> Result<Vec<Log>> {
+        let mut result = Vec::new();
+        let receipts = storage
             .chain()
             .operations_ext_schema()
             .web3_receipts(from_block, to_block)
             .await
-            .map_err(|_| Error::internal_error())?;
+            .map_err(internal_error)?;
         for receipt in receipts {
-            let logs = self.logs_from_receipt(&mut transaction, receipt).await?;
-            let filtered = logs.into_iter().filter(|log| {
-                if !topics.is_empty() && !topics.contains(&log.topics[0]) {
-                    return false;
-                }
-                if !addresses.is_empty() && !addresses.contains(&log.address) {
-                    return false;
-                }
-                true
-            });
-            result.extend(filtered);
+            let logs = self.logs_from_receipt(storage, receipt).await?;
+            result.extend(logs.into_iter().filter(|log| filter.matches(log)));
         }
+        Ok(result)
+    }
 
-        transaction
-            .commit()
+    /// Loads all the logs of the blocks from the range along with the block hashes.
+    async fn block_logs(
+        &self,
+        storage: &mut StorageProcessor<'_>,
+        from_block: zksync_types::BlockNumber,
+        to_block: zksync_types::BlockNumber,
+    ) -> Result<Vec<(zksync_types::BlockNumber, H256, Vec<Log>)>> {
+        let receipts = storage
+            .chain()
+            .operations_ext_schema()
+            .web3_receipts(from_block, to_block)
             .await
-            .map_err(|_| Error::internal_error())?;
+            .map_err(internal_error)?;
+        let mut logs_by_block: HashMap<i64, Vec<Log>> = HashMap::new();
+        for receipt in receipts {
+            let block_number = receipt.block_number;
+            let logs = self.logs_from_receipt(storage, receipt).await?;
+            logs_by_block.entry(block_number).or_default().extend(logs);
+        }
 
-        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_logs");
+        let mut result = Vec::new();
+        for block_number in from_block.0..=to_block.0 {
+            let block_number = zksync_types::BlockNumber(block_number);
+            // The range was already checked to be in storage.
+            let block = Self::storage_block(storage, block_number)
+                .await?
+                .ok_or_else(Error::internal_error)?;
+            let logs = logs_by_block
+                .remove(&(block_number.0 as i64))
+                .unwrap_or_default();
+            result.push((block_number, H256::from_slice(&block.root_hash), logs));
+        }
         Ok(result)
     }
 
+    /// Appends the logs of the blocks saved since the last update to the logs index,
+    /// after removing the reverted blocks from it.
+    pub(crate) async fn update_logs_index(&self, storage: &mut StorageProcessor<'_>) -> Result<()> {
+        if !self.logs_index.read().unwrap().is_enabled() {
+            return Ok(());
+        }
+
+        let last_saved_block = storage
+            .chain()
+            .block_schema()
+            .get_last_saved_block()
+            .await
+            .map_err(internal_error)?;
+        self.drop_reverted_logs(storage, last_saved_block).await?;
+        let next_block = self.logs_index.read().unwrap().next_block(last_saved_block);
+        if next_block > last_saved_block {
+            return Ok(());
+        }
+
+        let new_blocks = self
+            .block_logs(storage, next_block, last_saved_block)
+            .await?;
+        let mut index = self.logs_index.write().unwrap();
+        for (block_number, block_hash, logs) in new_blocks {
+            index.append_block(block_number, block_hash, logs);
+        }
+        Ok(())
+    }
+
+    /// Removes the reverted blocks from the logs index, checking the last indexed block
+    /// against the storage.
+    async fn drop_reverted_logs(
+        &self,
+        storage: &mut StorageProcessor<'_>,
+        last_saved_block: zksync_types::BlockNumber,
+    ) -> Result<()> {
+        let last_indexed_block = {
+            let mut index = self.logs_index.write().unwrap();
+            index.truncate(last_saved_block);
+            index.last_block()
+        };
+        if let Some((block_number, block_hash)) = last_indexed_block {
+            let stored_hash = Self::storage_block(storage, block_number)
+                .await?
+                .map(|block| H256::from_slice(&block.root_hash));
+            if stored_hash != Some(block_hash) {
+                // Reverted blocks were replaced with new blocks with the same numbers.
+                self.logs_index.write().unwrap().clear();
+            }
+        }
+        Ok(())
+    }
+
     pub async fn _impl_call(self, req: CallRequest, _block: Option<BlockNumber>) -> Result<Bytes> {
         let start = Instant::now();
         let mut storage = self.access_storage().await?;
@@ -315,6 +633,74 @@ impl Web3RpcApp {
         result.map(Bytes)
     }
 
+    /// Returns fee history of `block_count` blocks ending with `newest_block`.
+    /// `block_count` is clamped to the configured maximum instead of returning an error.
+    /// Reward percentiles must be strictly increasing values within `[0, 100]`.
+    pub async fn _impl_fee_history(
+        self,
+        block_count: U64,
+        newest_block: BlockNumber,
+        reward_percentiles: Option<Vec<f64>>,
+    ) -> Result<FeeHistory> {
+        let start = Instant::now();
+        if let Some(percentiles) = &reward_percentiles {
+            self.check_reward_percentiles(percentiles)?;
+        }
+        let mut storage = self.access_storage().await?;
+
+        let newest_block = self
+            .resolve_block_number(&mut storage, Some(newest_block), "newestBlock")
+            .await?
+            .ok_or_else(|| Error::invalid_params("Block with such number doesn't exist yet"))?;
+        let block_count = block_count
+            .as_u64()
+            .min(self.max_fee_history_block_count)
+            .min(newest_block.0 as u64 + 1);
+        let oldest_block = newest_block.0 as u64 + 1 - block_count;
+
+        // zkSync transactions don't pay for gas, so all the fees are zero.
+        let result = FeeHistory {
+            oldest_block: oldest_block.into(),
+            base_fee_per_gas: vec![U256::zero(); block_count as usize + 1],
+            gas_used_ratio: (oldest_block..=newest_block.0 as u64)
+                .map(|_| BlockInfo::gas_used_ratio())
+                .collect(),
+            reward: reward_percentiles.map(|percentiles| {
+                vec![vec![U256::zero(); percentiles.len()]; block_count as usize]
+            }),
+        };
+
+        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "fee_history");
+        Ok(result)
+    }
+
+    fn check_reward_percentiles(&self, percentiles: &[f64]) -> Result<()> {
+        if percentiles.len() as u64 > self.max_fee_history_reward_percentiles {
+            return Err(invalid_param(
+                "rewardPercentiles",
+                format!(
+                    "number of percentiles must not be greater than {}",
+                    self.max_fee_history_reward_percentiles
+                ),
+            ));
+        }
+        for (i, &percentile) in percentiles.iter().enumerate() {
+            if !(0.0..=100.0).contains(&percentile) {
+                return Err(invalid_param(
+                    "rewardPercentiles",
+                    format!("percentile {} is not within [0, 100]", percentile),
+                ));
+            }
+            if i > 0 && percentile <= percentiles[i - 1] {
+                return Err(invalid_param(
+                    "rewardPercentiles",
+                    "percentiles must be strictly increasing",
+                ));
+            }
+        }
+        Ok(())
+    }
+
     pub async fn _impl_check_withdrawal(
         self,
         tx_hash: H256,
@@ -326,12 +712,23 @@ impl Web3RpcApp {
             .withdrawals_schema()
             .get_finalized_withdrawals(tx_hash)
             .await
-            .map_err(|_| Error::internal_error())?;
+            .map_err(internal_error)?;
 
         metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "check_withdrawal");
         Ok(withdrawals)
     }
 
+    pub async fn _impl_get_tokens(self) -> Result<Vec<TokenInfo>> {
+        let start = Instant::now();
+        let mut storage = self.access_storage().await?;
+
+        let tokens = self.logs_helper.tokens(&mut storage).await?;
+        let result = tokens.into_iter().map(TokenInfo::from).collect();
+
+        metrics::histogram!("api", start.elapsed(), "type" => "web3", "endpoint_name" => "get_tokens");
+        Ok(result)
+    }
+
     pub(crate) async fn logs_from_receipt(
         &self,
         storage: &mut StorageProcessor<'_>,
@@ -361,6 +758,25 @@ impl Web3RpcApp {
         Ok(logs)
     }
 
+    /// Returns the receipt of the transaction with the given hash. Transactions of reverted
+    /// blocks have no receipt, even if they are still stored as executed.
+    pub(crate) async fn receipt_by_hash(
+        &self,
+        storage: &mut StorageProcessor<'_>,
+        hash: H256,
+    ) -> Result<Option<TransactionReceipt>> {
+        let tx = storage
+            .chain()
+            .operations_ext_schema()
+            .web3_receipt_by_hash(hash.as_ref())
+            .await
+            .map_err(internal_error)?;
+        match tx {
+            Some(tx) => Ok(Some(self.tx_receipt(storage, tx).await?)),
+            None => Ok(None),
+        }
+    }
+
     pub(crate) async fn tx_receipt(
         &self,
         storage: &mut StorageProcessor<'_>,
@@ -388,6 +804,39 @@ impl Web3RpcApp {
         })
     }
 
+    /// Resolves the block tag passed to any method into the block number.
+    /// This is the only place where the meaning of the `pending` and `latest` tags is decided,
+    /// an omitted tag means `latest`.
+    /// Resolved tags are logged at the trace level to debug unexpected responses.
+    /// `param` is the name of the parameter the tag was passed in, it's used in error messages.
+    async fn resolve_block_number(
+        &self,
+        storage: &mut StorageProcessor<'_>,
+        number: Option<BlockNumber>,
+        param: &str,
+    ) -> Result<Option<zksync_types::BlockNumber>> {
+        let effective_number = match number {
+            Some(BlockNumber::Pending) if !self.pending_includes_unconfirmed_blocks => {
+                Some(BlockNumber::Latest)
+            }
+            number => number,
+        };
+        let effective_number = match effective_number {
+            None | Some(BlockNumber::Latest) if self.latest_includes_unfinalized_blocks => {
+                Some(BlockNumber::Committed)
+            }
+            number => number,
+        };
+        let resolved = resolve_block_number(storage, effective_number, param).await?;
+        vlog::trace!(
+            "Block tag {:?} of `{}` is resolved to {:?}",
+            number,
+            param,
+            resolved
+        );
+        Ok(resolved)
+    }
+
     async fn storage_block(
         storage: &mut StorageProcessor<'_>,
         block_number: zksync_types::BlockNumber,
@@ -397,7 +846,7 @@ impl Web3RpcApp {
             .block_schema()
             .get_storage_block(block_number)
             .await
-            .map_err(|_| Error::internal_error())?;
+            .map_err(internal_error)?;
         Ok(block)
     }
 
@@ -406,10 +855,7 @@ impl Web3RpcApp {
         block_number: zksync_types::BlockNumber,
         include_txs: bool,
     ) -> Result<BlockInfo> {
-        let mut transaction = storage
-            .start_transaction()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        let mut transaction = storage.start_transaction().await.map_err(internal_error)?;
 
         let parent_hash = if block_number.0 == 0 {
             H256::zero()
@@ -428,7 +874,7 @@ impl Web3RpcApp {
                 .block_schema()
                 .get_block(block_number)
                 .await
-                .map_err(|_| Error::internal_error())?
+                .map_err(internal_error)?
                 .ok_or_else(Error::internal_error)?;
             let hash = H256::from_slice(&block.new_root_hash.to_bytes());
             let transactions = block
@@ -444,6 +890,7 @@ impl Web3RpcApp {
                             to: tx.signed_tx.tx.to_account(),
                             nonce: tx.signed_tx.tx.nonce().0,
                             tx_hash: H256::from_slice(tx.signed_tx.tx.hash().as_ref()),
+                            value: tx.op.as_ref().map(tx_value).unwrap_or_default(),
                         },
                         ExecutedOperations::PriorityOp(op) => TxData {
                             block_hash: hash,
@@ -453,6 +900,7 @@ impl Web3RpcApp {
                             to: Some(op.priority_op.data.to_account()),
                             nonce: op.priority_op.serial_id as u32,
                             tx_hash: H256::from_slice(op.priority_op.tx_hash().as_ref()),
+                            value: U256::zero(),
                         },
                     };
                     transaction_from_tx_data(tx)
@@ -476,7 +924,7 @@ impl Web3RpcApp {
                 .block_schema()
                 .get_block_transactions_hashes(block_number)
                 .await
-                .map_err(|_| Error::internal_error())?
+                .map_err(internal_error)?
                 .into_iter()
                 .map(|hash| H256::from_slice(&hash))
                 .collect();
@@ -489,10 +937,7 @@ impl Web3RpcApp {
                 hashes,
             )
         };
-        transaction
-            .commit()
-            .await
-            .map_err(|_| Error::internal_error())?;
+        transaction.commit().await.map_err(internal_error)?;
         Ok(result)
     }
 
@@ -505,7 +950,7 @@ impl Web3RpcApp {
             .block_schema()
             .get_block_transactions_count(block_number)
             .await
-            .map_err(|_| Error::internal_error())?;
+            .map_err(internal_error)?;
         Ok(U256::from(count))
     }
 }
@@ -4,9 +4,11 @@ use jsonrpc_derive::rpc;
 use zksync_types::withdrawals::WithdrawalPendingEvent;
 // Local uses
 use super::{
+    error::sanitize_error,
     types::{
-        BlockInfo, BlockNumber, Bytes, CallRequest, Filter, Log, Transaction, TransactionReceipt,
-        H160, H256, U256, U64,
+        BlockCountParam, BlockInfo, BlockParam, Bytes, CallRequest, FeeHistory, Filter, Log,
+        LogsWithMeta, NewestBlockParam, TokenInfo, Transaction, TransactionDetails,
+        TransactionReceipt, H160, H256, U256, U64,
     },
     Web3RpcApp,
 };
@@ -16,7 +18,13 @@ pub type BoxFutureResult<T> = BoxFuture<Result<T>>;
 macro_rules! spawn {
     ($self: ident.$method: ident($($args: expr),*)) => {{
         let self_ = $self.clone();
-        Box::pin(self_.$method($($args),*))
+        let reveal_internal_errors = $self.reveal_internal_errors;
+        Box::pin(async move {
+            self_
+                .$method($($args),*)
+                .await
+                .map_err(|err| sanitize_error(err, reveal_internal_errors))
+        })
     }}
 }
 
@@ -47,13 +55,28 @@ pub trait Web3Rpc {
     fn get_uncle_count_by_block_hash(&self, block_hash: H256) -> Result<U256>;
 
     #[rpc(name = "eth_getUncleCountByBlockNumber", returns = "U256")]
-    fn get_uncle_count_by_block_number(&self, block_number: BlockNumber) -> Result<U256>;
+    fn get_uncle_count_by_block_number(&self, block_number: BlockParam) -> Result<U256>;
 
     #[rpc(name = "eth_blockNumber", returns = "U64")]
     fn block_number(&self) -> BoxFutureResult<U64>;
 
     #[rpc(name = "eth_getBalance", returns = "U256")]
-    fn get_balance(&self, address: H160, block: Option<BlockNumber>) -> BoxFutureResult<U256>;
+    fn get_balance(&self, address: H160, block: Option<BlockParam>) -> BoxFutureResult<U256>;
+
+    #[rpc(name = "zks_getTokenBalance", returns = "U256")]
+    fn get_token_balance(
+        &self,
+        address: H160,
+        token: H160,
+        block: Option<BlockParam>,
+    ) -> BoxFutureResult<U256>;
+
+    #[rpc(name = "eth_getTransactionCount", returns = "U256")]
+    fn get_transaction_count(
+        &self,
+        address: H160,
+        block: Option<BlockParam>,
+    ) -> BoxFutureResult<U256>;
 
     #[rpc(name = "eth_getBlockTransactionCountByHash", returns = "Option<U256>")]
     fn get_block_transaction_count_by_hash(&self, hash: H256) -> BoxFutureResult<Option<U256>>;
@@ -64,16 +87,22 @@ pub trait Web3Rpc {
     )]
     fn get_block_transaction_count_by_number(
         &self,
-        block: Option<BlockNumber>,
+        block: Option<BlockParam>,
     ) -> BoxFutureResult<Option<U256>>;
 
     #[rpc(name = "eth_getTransactionByHash", returns = "Option<Transaction>")]
     fn get_transaction_by_hash(&self, hash: H256) -> BoxFutureResult<Option<Transaction>>;
 
+    #[rpc(
+        name = "zks_getTransactionDetails",
+        returns = "Option<TransactionDetails>"
+    )]
+    fn get_transaction_details(&self, hash: H256) -> BoxFutureResult<Option<TransactionDetails>>;
+
     #[rpc(name = "eth_getBlockByNumber", returns = "Option<BlockInfo>")]
     fn get_block_by_number(
         &self,
-        block_number: Option<BlockNumber>,
+        block_number: Option<BlockParam>,
         include_txs: bool,
     ) -> BoxFutureResult<Option<BlockInfo>>;
 
@@ -90,14 +119,31 @@ pub trait Web3Rpc {
     )]
     fn get_transaction_receipt(&self, hash: H256) -> BoxFutureResult<Option<TransactionReceipt>>;
 
+    #[rpc(name = "zks_getTransactionLogs", returns = "Option<Vec<Log>>")]
+    fn get_transaction_logs(&self, hash: H256) -> BoxFutureResult<Option<Vec<Log>>>;
+
     #[rpc(name = "eth_getLogs", returns = "Vec<Log>")]
     fn get_logs(&self, filter: Filter) -> BoxFutureResult<Vec<Log>>;
 
+    #[rpc(name = "zks_getLogsWithMeta", returns = "LogsWithMeta")]
+    fn get_logs_with_meta(&self, filter: Filter) -> BoxFutureResult<LogsWithMeta>;
+
     #[rpc(name = "eth_call", returns = "Bytes")]
-    fn call(&self, req: CallRequest, _block: Option<BlockNumber>) -> BoxFutureResult<Bytes>;
+    fn call(&self, req: CallRequest, _block: Option<BlockParam>) -> BoxFutureResult<Bytes>;
+
+    #[rpc(name = "eth_feeHistory", returns = "FeeHistory")]
+    fn fee_history(
+        &self,
+        block_count: BlockCountParam,
+        newest_block: NewestBlockParam,
+        reward_percentiles: Option<Vec<f64>>,
+    ) -> BoxFutureResult<FeeHistory>;
 
     #[rpc(name = "zksync_checkWithdrawal", returns = "Vec<String>")]
     fn check_withdrawal(&self, tx_hash: H256) -> BoxFutureResult<Vec<WithdrawalPendingEvent>>;
+
+    #[rpc(name = "zks_getTokens", returns = "Vec<TokenInfo>")]
+    fn get_tokens(&self) -> BoxFutureResult<Vec<TokenInfo>>;
 }
 
 impl Web3Rpc for Web3RpcApp {
@@ -133,7 +179,7 @@ impl Web3Rpc for Web3RpcApp {
         Ok(U256::zero())
     }
 
-    fn get_uncle_count_by_block_number(&self, _block_number: BlockNumber) -> Result<U256> {
+    fn get_uncle_count_by_block_number(&self, _block_number: BlockParam) -> Result<U256> {
         Ok(U256::zero())
     }
 
@@ -141,8 +187,25 @@ impl Web3Rpc for Web3RpcApp {
         spawn!(self._impl_block_number())
     }
 
-    fn get_balance(&self, address: H160, block: Option<BlockNumber>) -> BoxFutureResult<U256> {
-        spawn!(self._impl_get_balance(address, block))
+    fn get_balance(&self, address: H160, block: Option<BlockParam>) -> BoxFutureResult<U256> {
+        spawn!(self._impl_get_balance(address, block.map(|block| block.0)))
+    }
+
+    fn get_token_balance(
+        &self,
+        address: H160,
+        token: H160,
+        block: Option<BlockParam>,
+    ) -> BoxFutureResult<U256> {
+        spawn!(self._impl_get_token_balance(address, token, block.map(|block| block.0)))
+    }
+
+    fn get_transaction_count(
+        &self,
+        address: H160,
+        block: Option<BlockParam>,
+    ) -> BoxFutureResult<U256> {
+        spawn!(self._impl_get_transaction_count(address, block.map(|block| block.0)))
     }
 
     fn get_block_transaction_count_by_hash(&self, hash: H256) -> BoxFutureResult<Option<U256>> {
@@ -151,21 +214,25 @@ impl Web3Rpc for Web3RpcApp {
 
     fn get_block_transaction_count_by_number(
         &self,
-        block: Option<BlockNumber>,
+        block: Option<BlockParam>,
     ) -> BoxFutureResult<Option<U256>> {
-        spawn!(self._impl_get_block_transaction_count_by_number(block))
+        spawn!(self._impl_get_block_transaction_count_by_number(block.map(|block| block.0)))
     }
 
     fn get_transaction_by_hash(&self, hash: H256) -> BoxFutureResult<Option<Transaction>> {
         spawn!(self._impl_get_transaction_by_hash(hash))
     }
 
+    fn get_transaction_details(&self, hash: H256) -> BoxFutureResult<Option<TransactionDetails>> {
+        spawn!(self._impl_get_transaction_details(hash))
+    }
+
     fn get_block_by_number(
         &self,
-        block_number: Option<BlockNumber>,
+        block_number: Option<BlockParam>,
         include_txs: bool,
     ) -> BoxFutureResult<Option<BlockInfo>> {
-        spawn!(self._impl_get_block_by_number(block_number, include_txs))
+        spawn!(self._impl_get_block_by_number(block_number.map(|block| block.0), include_txs))
     }
 
     fn get_block_by_hash(
@@ -180,15 +247,36 @@ impl Web3Rpc for Web3RpcApp {
         spawn!(self._impl_get_transaction_receipt(hash))
     }
 
+    fn get_transaction_logs(&self, hash: H256) -> BoxFutureResult<Option<Vec<Log>>> {
+        spawn!(self._impl_get_transaction_logs(hash))
+    }
+
     fn get_logs(&self, filter: Filter) -> BoxFutureResult<Vec<Log>> {
         spawn!(self._impl_get_logs(filter))
     }
 
-    fn call(&self, req: CallRequest, block: Option<BlockNumber>) -> BoxFutureResult<Bytes> {
-        spawn! { self._impl_call(req, block) }
+    fn get_logs_with_meta(&self, filter: Filter) -> BoxFutureResult<LogsWithMeta> {
+        spawn!(self._impl_get_logs_with_meta(filter))
+    }
+
+    fn call(&self, req: CallRequest, block: Option<BlockParam>) -> BoxFutureResult<Bytes> {
+        spawn!(self._impl_call(req, block.map(|block| block.0)))
+    }
+
+    fn fee_history(
+        &self,
+        block_count: BlockCountParam,
+        newest_block: NewestBlockParam,
+        reward_percentiles: Option<Vec<f64>>,
+    ) -> BoxFutureResult<FeeHistory> {
+        spawn!(self._impl_fee_history(block_count.0, newest_block.0, reward_percentiles))
     }
 
     fn check_withdrawal(&self, tx_hash: H256) -> BoxFutureResult<Vec<WithdrawalPendingEvent>> {
-        spawn! { self._impl_check_withdrawal(tx_hash) }
+        spawn!(self._impl_check_withdrawal(tx_hash))
+    }
+
+    fn get_tokens(&self) -> BoxFutureResult<Vec<TokenInfo>> {
+        spawn!(self._impl_get_tokens())
     }
 }
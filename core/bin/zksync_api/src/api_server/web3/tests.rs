@@ -1,26 +1,49 @@
 // Built-in uses
+use std::io;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 // External uses
+use chrono::Utc;
 use ethabi::{ParamType, Token};
 use futures::future::{join, join5, Future};
-use jsonrpc_core::{Error, ErrorCode, IoHandler, Params};
+use jsonrpc_core::{Error, ErrorCode, IoHandler, MetaIoHandler, Params};
 use jsonrpc_core_client::{RawClient, RpcError, RpcResult};
 use num::BigUint;
-use serde_json::{Map, Value};
+use serde::{de, Deserialize, Deserializer};
+use serde_json::{json, Map, Value};
 // Workspace uses
 
-use zksync_storage::{chain::operations_ext::records::Web3TxReceipt, ConnectionPool};
+use zksync_crypto::rand::{Rng, SeedableRng, XorShiftRng};
+use zksync_storage::{
+    chain::operations_ext::records::{Web3TxData, Web3TxReceipt},
+    test_data::{gen_sample_block, BLOCK_SIZE_CHUNKS},
+    ConnectionPool,
+};
 use zksync_test_account::ZkSyncAccount;
 use zksync_types::{
     tx::ChangePubKeyType, AccountId, AccountUpdate, BlockNumber, ChangePubKeyOp, CloseOp, Deposit,
-    DepositOp, ForcedExitOp, FullExit, FullExitOp, MintNFTOp, Nonce, SwapOp, TokenId, TransferOp,
-    WithdrawNFTOp, WithdrawOp, ZkSyncOp, NFT,
+    DepositOp, ExecutedOperations, ForcedExitOp, FullExit, FullExitOp, MintNFTOp, Nonce,
+    SignedZkSyncTx, SwapOp, Token, TokenId, TokenKind, TransferOp, WithdrawNFTOp, WithdrawOp,
+    ZkSyncOp, ZkSyncTx, NFT,
 };
 // Local uses
 use super::{
     calls::CallsHelper,
-    converter::{transaction_from_tx_data, u256_from_biguint},
-    types::{BlockInfo, Event, Log, Transaction, TransactionReceipt, H160, H256, U256, U64},
+    converter::{
+        storage_block_number, transaction_from_tx_data, tx_data_from_web3, tx_value,
+        u256_from_biguint,
+    },
+    error::{internal_error, sanitize_error},
+    logs::{LogsFilter, LogsHelper},
+    logs_index::LogsIndex,
+    types::{
+        serialize_data, serialize_quantity, BlockInfo, BlockNumber as Web3BlockNumber, Bytes,
+        CallRequest, CommonLogData, Event, FeeHistory, Filter, Log, LogsWithMeta, TokenInfo,
+        Transaction, TransactionDetails, TransactionReceipt, TxData, ValueOrArray, H160, H256, H64,
+        U256, U64,
+    },
+    version_middleware::JsonRpcVersionMiddleware,
     Web3RpcApp, NFT_FACTORY_ADDRESS, ZKSYNC_PROXY_ADDRESS,
 };
 use crate::api_server::rest::v02::test_utils::TestServerConfig;
@@ -30,15 +53,19 @@ async fn local_client() -> anyhow::Result<(RawClient, impl Future<Output = RpcRe
     let cfg = TestServerConfig::default();
     cfg.fill_database().await?;
 
-    let rpc_app = Web3RpcApp::new(cfg.pool, &cfg.config.api.web3, &cfg.config.api.token_config);
+    Ok(local_client_with_config(cfg.pool, &cfg.config.api.web3))
+}
+
+/// Creates a client for the server with the given config. The database is not filled.
+fn local_client_with_config(
+    pool: ConnectionPool,
+    config: &Web3Config,
+) -> (RawClient, impl Future<Output = RpcResult<()>>) {
+    let rpc_app = Web3RpcApp::new(pool, config, &TokenConfig::from_env());
     let mut io = IoHandler::new();
     rpc_app.extend(&mut io);
 
-    Ok(jsonrpc_core_client::transports::local::connect::<
-        RawClient,
-        _,
-        _,
-    >(io))
+    jsonrpc_core_client::transports::local::connect::<RawClient, _, _>(io)
 }
 
 /// Checks that static methods return values they should return
@@ -104,6 +131,45 @@ async fn static_methods() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Checks that responses echo the request `id` with its original JSON type, including batch entries.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn response_id_type_preserved() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    let rpc_app = Web3RpcApp::new(cfg.pool, &cfg.config.api.web3, &cfg.config.api.token_config);
+    let mut io = IoHandler::new();
+    rpc_app.extend(&mut io);
+
+    let ids = vec![json!(1), json!("1"), json!("request-id"), Value::Null];
+    for id in ids.clone() {
+        let request = json!({"jsonrpc": "2.0", "method": "net_version", "params": [], "id": id});
+        let response = io.handle_request(&request.to_string()).await.unwrap();
+        let response: Value = serde_json::from_str(&response)?;
+        assert_eq!(response["id"], id);
+    }
+
+    let batch: Vec<_> = ids
+        .iter()
+        .map(|id| json!({"jsonrpc": "2.0", "method": "net_version", "params": [], "id": id}))
+        .collect();
+    let response = io
+        .handle_request(&Value::Array(batch).to_string())
+        .await
+        .unwrap();
+    let response: Value = serde_json::from_str(&response)?;
+    let response_ids: Vec<_> = response
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["id"].clone())
+        .collect();
+    assert_eq!(response_ids, ids);
+    Ok(())
+}
+
 /// Tests `eth_blockNumber` method
 #[tokio::test(flavor = "multi_thread")]
 #[cfg_attr(
@@ -367,7 +433,7 @@ async fn get_transaction_by_hash() -> anyhow::Result<()> {
             .tx_data_for_web3(&tx_hash)
             .await?
             .unwrap();
-        transaction_from_tx_data(tx_data.into())
+        transaction_from_tx_data(tx_data_from_web3(tx_data).unwrap())
     };
     assert_eq!(
         serde_json::from_value::<Transaction>(transaction).unwrap(),
@@ -868,6 +934,64 @@ async fn get_transaction_receipt() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Checks that `eth_getTransactionReceipt` returns `null` for transactions of reverted blocks.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn transaction_receipt_of_reverted_block() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let rpc_app = Web3RpcApp::new(
+        cfg.pool.clone(),
+        &cfg.config.api.web3,
+        &cfg.config.api.token_config,
+    );
+
+    // The block is reverted within a DB transaction that is never committed,
+    // so the database stays intact for the other tests.
+    let mut storage = cfg.pool.access_storage().await?;
+    let mut transaction = storage.start_transaction().await?;
+    // Not every block has transactions, so the last committed one that has them is reverted.
+    let mut block_number = transaction
+        .chain()
+        .block_schema()
+        .get_last_committed_confirmed_block()
+        .await?;
+    let tx_hash = loop {
+        let tx_hashes = transaction
+            .chain()
+            .block_schema()
+            .get_block_transactions_hashes(block_number)
+            .await?;
+        if let Some(tx_hash) = tx_hashes.first() {
+            break H256::from_slice(tx_hash);
+        }
+        block_number = block_number - 1;
+    };
+
+    let receipt = rpc_app
+        .receipt_by_hash(&mut transaction, tx_hash)
+        .await
+        .unwrap();
+    assert_eq!(receipt.unwrap().transaction_hash, tx_hash);
+
+    transaction
+        .chain()
+        .block_schema()
+        .remove_blocks(block_number - 1)
+        .await?;
+    let receipt = rpc_app
+        .receipt_by_hash(&mut transaction, tx_hash)
+        .await
+        .unwrap();
+    assert_eq!(serde_json::to_value(receipt)?, Value::Null);
+    drop(transaction);
+
+    Ok(())
+}
+
 /// Tests `eth_getLogs` method
 #[tokio::test(flavor = "multi_thread")]
 #[cfg_attr(
@@ -910,12 +1034,17 @@ async fn get_logs() -> anyhow::Result<()> {
                 url: "".to_string(),
                 max_block_range: 3,
                 chain_id: 9,
+                max_fee_history_block_count: 1024,
+                max_fee_history_reward_percentiles: 100,
+                logs_index_max_blocks: 128,
+                logs_index_update_interval_sec: 1,
+                pending_includes_unconfirmed_blocks: true,
+                latest_includes_unfinalized_blocks: false,
+                omit_total_difficulty: false,
+                reveal_internal_errors: false,
+                stuck_tx_threshold_sec: 600,
             };
-            let rpc_app = Web3RpcApp::new(pool.clone(), &config, &TokenConfig::from_env());
-            let mut io = IoHandler::new();
-            rpc_app.extend(&mut io);
-
-            jsonrpc_core_client::transports::local::connect::<RawClient, _, _>(io)
+            local_client_with_config(pool.clone(), &config)
         };
         let mut req = Map::new();
         req.insert("fromBlock".to_string(), Value::String("0x1".to_string()));
@@ -1001,6 +1130,37 @@ async fn get_logs() -> anyhow::Result<()> {
         assert!(addresses.contains(&log.address));
     }
 
+    // Checks that empty `topics` don't constrain the logs of the address.
+    let proxy_address = H160::from_str(ZKSYNC_PROXY_ADDRESS).unwrap();
+    let get_proxy_logs = |topics: Option<Value>| {
+        let mut req = Map::new();
+        req.insert("fromBlock".to_string(), Value::String("0x1".to_string()));
+        req.insert("toBlock".to_string(), Value::String("0x8".to_string()));
+        req.insert(
+            "address".to_string(),
+            serde_json::to_value(proxy_address).unwrap(),
+        );
+        if let Some(topics) = topics {
+            req.insert("topics".to_string(), topics);
+        }
+        async move {
+            let (client, server) = local_client().await?;
+            let fut = join(
+                client.call_method("eth_getLogs", Params::Array(vec![Value::Object(req)])),
+                server,
+            );
+            let logs = fut.await.0.unwrap();
+            Ok::<_, anyhow::Error>(serde_json::from_value::<Vec<Log>>(logs)?)
+        }
+    };
+    let all_proxy_logs = get_proxy_logs(None).await?;
+    assert!(!all_proxy_logs.is_empty());
+    assert_eq!(get_proxy_logs(Some(json!([]))).await?, all_proxy_logs);
+    assert_eq!(get_proxy_logs(Some(json!([null]))).await?, all_proxy_logs);
+    assert!(all_proxy_logs
+        .iter()
+        .all(|log| log.address == proxy_address));
+
     // Checks that topic filter works correctly
     let topics = vec![
         rpc_app
@@ -1042,6 +1202,239 @@ async fn get_logs() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that an empty `address` list of `eth_getLogs` matches no logs, unlike omitted `address`.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn get_logs_empty_address() -> anyhow::Result<()> {
+    let get_logs = |address: Option<Value>| {
+        let mut req = Map::new();
+        req.insert("fromBlock".to_string(), Value::String("0x1".to_string()));
+        req.insert("toBlock".to_string(), Value::String("0x8".to_string()));
+        if let Some(address) = address {
+            req.insert("address".to_string(), address);
+        }
+        async move {
+            let (client, server) = local_client().await?;
+            let fut = join(
+                client.call_method("eth_getLogs", Params::Array(vec![Value::Object(req)])),
+                server,
+            );
+            let logs = fut.await.0.unwrap();
+            Ok::<_, anyhow::Error>(serde_json::from_value::<Vec<Log>>(logs)?)
+        }
+    };
+    assert!(get_logs(Some(json!([]))).await?.is_empty());
+    assert!(!get_logs(None).await?.is_empty());
+    assert_eq!(get_logs(Some(Value::Null)).await?, get_logs(None).await?);
+
+    Ok(())
+}
+
+/// Tests that `eth_getLogs` filtered by a token address returns only the logs of that token.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn get_logs_token_address() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let (eth_address, phnx_address) = {
+        let mut storage = cfg.pool.access_storage().await?;
+        let eth = storage
+            .tokens_schema()
+            .get_token(TokenId(0).into())
+            .await?
+            .unwrap();
+        let phnx = storage
+            .tokens_schema()
+            .get_token(TokenId(1).into())
+            .await?
+            .unwrap();
+        (eth.address, phnx.address)
+    };
+
+    let get_logs = |address: Option<H160>| {
+        let mut req = Map::new();
+        req.insert("fromBlock".to_string(), Value::String("0x1".to_string()));
+        req.insert("toBlock".to_string(), Value::String("0x8".to_string()));
+        if let Some(address) = address {
+            req.insert("address".to_string(), json!(address));
+        }
+        async move {
+            let (client, server) = local_client().await?;
+            let fut = join(
+                client.call_method("eth_getLogs", Params::Array(vec![Value::Object(req)])),
+                server,
+            );
+            let logs = fut.await.0.unwrap();
+            Ok::<_, anyhow::Error>(serde_json::from_value::<Vec<Log>>(logs)?)
+        }
+    };
+
+    let all_logs = get_logs(None).await?;
+    let eth_logs = get_logs(Some(eth_address)).await?;
+    assert!(!eth_logs.is_empty());
+    assert!(eth_logs.len() < all_logs.len());
+    let expected_eth_logs: Vec<_> = all_logs
+        .into_iter()
+        .filter(|log| log.address == eth_address)
+        .collect();
+    assert_eq!(eth_logs, expected_eth_logs);
+
+    // The token is registered, but has never been transferred.
+    assert!(get_logs(Some(phnx_address)).await?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+/// Tests that an empty `address` list survives a serialization round-trip
+/// instead of turning into `null`, which matches logs of any address.
+fn empty_address_filter_round_trip() {
+    let filter = Filter {
+        address: Some(ValueOrArray(Vec::new())),
+        ..Filter::default()
+    };
+    let value = serde_json::to_value(&filter).unwrap();
+    assert_eq!(value["address"], json!([]));
+    assert_eq!(serde_json::from_value::<Filter>(value).unwrap(), filter);
+
+    let filter = Filter::default();
+    let value = serde_json::to_value(&filter).unwrap();
+    assert_eq!(value["address"], Value::Null);
+    assert_eq!(serde_json::from_value::<Filter>(value).unwrap(), filter);
+}
+
+/// Tests `zks_getLogsWithMeta` method
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn get_logs_with_meta() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let (last_finalized_block, last_saved_block) = {
+        let mut storage = cfg.pool.access_storage().await?;
+        let last_finalized_block = storage
+            .chain()
+            .block_schema()
+            .get_last_verified_confirmed_block()
+            .await?;
+        let last_saved_block = storage
+            .chain()
+            .block_schema()
+            .get_last_saved_block()
+            .await?;
+        (last_finalized_block, last_saved_block)
+    };
+    assert!(last_finalized_block < last_saved_block);
+
+    let get_logs_with_meta = |from_block: &str, to_block: &str| {
+        let mut req = Map::new();
+        req.insert(
+            "fromBlock".to_string(),
+            Value::String(from_block.to_string()),
+        );
+        req.insert("toBlock".to_string(), Value::String(to_block.to_string()));
+        async move {
+            let (client, server) = local_client().await?;
+            let fut = join(
+                client.call_method(
+                    "zks_getLogsWithMeta",
+                    Params::Array(vec![Value::Object(req)]),
+                ),
+                server,
+            );
+            let response = fut.await.0.unwrap();
+            Ok::<_, anyhow::Error>(serde_json::from_value::<LogsWithMeta>(response)?)
+        }
+    };
+
+    // The range covers non-finalized blocks, so the finalized head is reported.
+    let response = get_logs_with_meta("0x1", "pending").await?;
+    assert_eq!(response.finalized_block, U64::from(last_finalized_block.0));
+    assert!(response
+        .logs
+        .iter()
+        .any(|log| log.block_number.unwrap() > response.finalized_block));
+
+    // The whole range is finalized, so the reported block doesn't exceed the range.
+    let response = get_logs_with_meta("0x1", "0x1").await?;
+    assert_eq!(response.finalized_block, U64::one());
+    assert!(response
+        .logs
+        .iter()
+        .all(|log| log.block_number.unwrap() <= response.finalized_block));
+
+    Ok(())
+}
+
+/// Tests `eth_feeHistory` method
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn fee_history() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let config = Web3Config {
+        max_fee_history_block_count: 2,
+        ..cfg.config.api.web3.clone()
+    };
+
+    // Checks that `blockCount` exceeding the maximum is clamped rather than rejected.
+    let fut = {
+        let (client, server) = local_client_with_config(cfg.pool.clone(), &config);
+        join(
+            client.call_method(
+                "eth_feeHistory",
+                Params::Array(vec![
+                    Value::String("0x186a0".to_string()),
+                    Value::String("0x5".to_string()),
+                    serde_json::to_value(vec![25.0, 75.0]).unwrap(),
+                ]),
+            ),
+            server,
+        )
+    };
+    let fee_history = serde_json::from_value::<FeeHistory>(fut.await.0.unwrap()).unwrap();
+    assert_eq!(fee_history.oldest_block, U64::from(4));
+    assert_eq!(fee_history.gas_used_ratio.len(), 2);
+    assert_eq!(fee_history.base_fee_per_gas.len(), 3);
+    assert_eq!(fee_history.reward.unwrap(), vec![vec![U256::zero(); 2]; 2]);
+
+    // Checks that gas used ratios match the gas figures reported for the blocks.
+    for (i, gas_used_ratio) in fee_history.gas_used_ratio.into_iter().enumerate() {
+        let block_number = fee_history.oldest_block + i;
+        let fut = {
+            let (client, server) = local_client().await?;
+            join(
+                client.call_method(
+                    "eth_getBlockByNumber",
+                    Params::Array(vec![json!(block_number), json!(false)]),
+                ),
+                server,
+            )
+        };
+        let block = fut.await.0.unwrap();
+        let gas_used: U256 = serde_json::from_value(block["gasUsed"].clone())?;
+        let gas_limit: U256 = serde_json::from_value(block["gasLimit"].clone())?;
+        assert!(!gas_limit.is_zero());
+        assert_eq!(
+            gas_used_ratio,
+            gas_used.as_u64() as f64 / gas_limit.as_u64() as f64
+        );
+    }
+
+    Ok(())
+}
+
 /// Tests `eth_call` method for erc20 contracts
 #[tokio::test(flavor = "multi_thread")]
 #[cfg_attr(
@@ -1053,13 +1446,18 @@ async fn erc20_calls() -> anyhow::Result<()> {
     cfg.fill_database().await?;
     let pool = ConnectionPool::new(Some(1));
 
-    let (token, address, balance) = {
+    let (token, address, balance, total_supply) = {
         let mut storage = pool.access_storage().await?;
         let token = storage
             .tokens_schema()
             .get_token(TokenId(1).into())
             .await?
             .unwrap();
+        let (_, accounts) = storage.chain().state_schema().load_verified_state().await?;
+        let total_supply: BigUint = accounts
+            .values()
+            .map(|account| account.get_balance(token.id))
+            .sum();
         let address = storage
             .chain()
             .account_schema()
@@ -1076,7 +1474,12 @@ async fn erc20_calls() -> anyhow::Result<()> {
             .account_schema()
             .get_account_balance_for_block(address, last_block, token.id)
             .await?;
-        (token, address, u256_from_biguint(balance))
+        (
+            token,
+            address,
+            u256_from_biguint(balance),
+            u256_from_biguint(total_supply),
+        )
     };
 
     // Test `name` function.
@@ -1172,7 +1575,7 @@ async fn erc20_calls() -> anyhow::Result<()> {
         &hex::decode(resp_data.strip_prefix("0x").unwrap()).unwrap(),
     )
     .unwrap();
-    assert_eq!(outputs[0].clone().into_uint().unwrap(), U256::max_value());
+    assert_eq!(outputs[0].clone().into_uint().unwrap(), total_supply);
 
     // Test `balanceOf` function.
     let fut = {
@@ -1226,7 +1629,7 @@ async fn erc20_calls() -> anyhow::Result<()> {
         &hex::decode(resp_data.strip_prefix("0x").unwrap()).unwrap(),
     )
     .unwrap();
-    assert_eq!(outputs[0].clone().into_uint().unwrap(), U256::max_value());
+    assert_eq!(outputs[0].clone().into_uint().unwrap(), U256::zero());
 
     Ok(())
 }
@@ -1515,3 +1918,2293 @@ fn ipfs_cid() {
     let ipfs_cid = CallsHelper::ipfs_cid(content_hash.as_bytes());
     assert_eq!(ipfs_cid, "QmQbSVaG7DUjQ9ktPtMnSXReJ29XHezBghcxJeZDsGG7wB")
 }
+
+#[test]
+/// Tests that logs of all token ids sharing one L1 address are matched by the address filter.
+fn logs_filter_address_with_multiple_token_ids() {
+    let logs_helper = LogsHelper::new(Duration::from_secs(1));
+    let address = H160::from_low_u64_be(0xdead);
+    // The same L1 token registered under two internal ids, e.g. after a migration.
+    let tokens = vec![
+        Token::new(TokenId(10), address, "MLTT", 18, TokenKind::ERC20),
+        Token::new(TokenId(11), address, "MLTT2", 18, TokenKind::ERC20),
+    ];
+    let common_data = CommonLogData {
+        block_hash: Some(H256::zero()),
+        block_number: Some(U64::one()),
+        transaction_hash: H256::zero(),
+        transaction_index: Some(U64::zero()),
+    };
+    let logs: Vec<Log> = tokens
+        .into_iter()
+        .enumerate()
+        .map(|(amount, token)| {
+            let mut index = U256::zero();
+            logs_helper.erc_transfer(
+                token,
+                H160::from_low_u64_be(1),
+                H160::from_low_u64_be(2),
+                BigUint::from(amount as u32 + 1),
+                common_data,
+                &mut index,
+            )
+        })
+        .collect();
+
+    let filter = LogsFilter::new(vec![address], Vec::new());
+    let matched: Vec<_> = logs.iter().filter(|log| filter.matches(log)).collect();
+    assert_eq!(matched.len(), 2);
+    assert_ne!(matched[0].data, matched[1].data);
+
+    let filter = LogsFilter::new(vec![H160::from_low_u64_be(0xbeef)], Vec::new());
+    assert!(!logs.iter().any(|log| filter.matches(log)));
+}
+
+#[test]
+/// Tests that withdrawals and forced exits of ETH report the withdrawn amount as `value`
+/// of the web3 transaction.
+fn withdraw_transaction_value() {
+    let from_account = ZkSyncAccount::rand_with_seed([1, 2, 3, 4]);
+    from_account.set_account_id(Some(AccountId(1)));
+    let to_address = H160::from_low_u64_be(0xbeef);
+    let amount = BigUint::from(100u32);
+    let withdraw_op = |token_id: TokenId, symbol: &str| {
+        let tx = from_account
+            .sign_withdraw(
+                token_id,
+                symbol,
+                amount.clone(),
+                BigUint::from(1u32),
+                &to_address,
+                None,
+                true,
+                Default::default(),
+            )
+            .0;
+        ZkSyncOp::Withdraw(Box::new(WithdrawOp {
+            tx,
+            account_id: AccountId(1),
+        }))
+    };
+    let forced_exit_op = |token_id: TokenId, withdraw_amount: Option<BigUint>| {
+        let tx = from_account.sign_forced_exit(
+            token_id,
+            BigUint::from(1u32),
+            &to_address,
+            None,
+            true,
+            Default::default(),
+        );
+        ZkSyncOp::ForcedExit(Box::new(ForcedExitOp {
+            tx,
+            target_account_id: AccountId(2),
+            withdraw_amount: withdraw_amount.map(Into::into),
+        }))
+    };
+    let web3_tx_data = |operation: Option<Value>| Web3TxData {
+        tx_hash: H256::zero().as_bytes().to_vec(),
+        block_number: 1,
+        nonce: 0,
+        block_hash: H256::zero().as_bytes().to_vec(),
+        block_index: Some(0),
+        from_account: from_account.address.as_bytes().to_vec(),
+        to_account: Some(to_address.as_bytes().to_vec()),
+        operation,
+    };
+    let value = |op: &ZkSyncOp| {
+        let tx_data =
+            tx_data_from_web3(web3_tx_data(Some(serde_json::to_value(op).unwrap()))).unwrap();
+        assert_eq!(tx_data.value, tx_value(op));
+        transaction_from_tx_data(tx_data).value
+    };
+
+    let eth_withdraw = withdraw_op(TokenId(0), "ETH");
+    assert_eq!(value(&eth_withdraw), u256_from_biguint(amount.clone()));
+    let eth_forced_exit = forced_exit_op(TokenId(0), Some(amount.clone()));
+    assert_eq!(value(&eth_forced_exit), u256_from_biguint(amount.clone()));
+    // Forced exit of an account without ETH doesn't withdraw anything.
+    let empty_forced_exit = forced_exit_op(TokenId(0), None);
+    assert_eq!(value(&empty_forced_exit), U256::zero());
+
+    // Amounts of other tokens are only reported by the `Transfer` logs.
+    let token_withdraw = withdraw_op(TokenId(1), "DAI");
+    assert_eq!(value(&token_withdraw), U256::zero());
+    let token_forced_exit = forced_exit_op(TokenId(1), Some(amount.clone()));
+    assert_eq!(value(&token_forced_exit), U256::zero());
+
+    // Priority operations and failed transactions don't move ETH.
+    let tx_data = tx_data_from_web3(web3_tx_data(None)).unwrap();
+    assert_eq!(tx_data.value, U256::zero());
+    let tx_data = tx_data_from_web3(web3_tx_data(Some(Value::Null))).unwrap();
+    assert_eq!(tx_data.value, U256::zero());
+
+    // Malformed stored operations are reported instead of being treated as zero `value`.
+    let malformed = web3_tx_data(Some(json!({ "type": "Withdraw" })));
+    assert!(tx_data_from_web3(malformed).is_err());
+}
+
+#[test]
+/// Tests that quantities are serialized without leading zeros.
+fn quantity_serialization() {
+    let quantity = |value: U256| serialize_quantity(&value, serde_json::value::Serializer).unwrap();
+    assert_eq!(quantity(U256::from(256)), json!("0x100"));
+    assert_eq!(quantity(U256::from(1)), json!("0x1"));
+    assert_eq!(quantity(U256::zero()), json!("0x0"));
+    for value in [0u64, 1, 256, u64::MAX] {
+        let number = serde_json::to_value(Web3BlockNumber::Number(U64::from(value))).unwrap();
+        assert_eq!(number, serde_json::to_value(U64::from(value)).unwrap());
+        assert_eq!(number, json!(format!("{:#x}", value)));
+    }
+
+    // Optional quantities and lists of quantities.
+    let quantity = |value: Option<Vec<U256>>| {
+        serialize_quantity(&value, serde_json::value::Serializer).unwrap()
+    };
+    assert_eq!(quantity(None), Value::Null);
+    assert_eq!(
+        quantity(Some(vec![
+            U256::zero(),
+            U256::from(0x0f),
+            U256::from(0x1000)
+        ])),
+        json!(["0x0", "0xf", "0x1000"])
+    );
+
+    // Quantity fields of the zkSync-specific types.
+    let fee_history = serde_json::to_value(FeeHistory {
+        oldest_block: U64::from(256),
+        base_fee_per_gas: vec![U256::zero(), U256::from(16)],
+        gas_used_ratio: vec![0.0],
+        reward: Some(vec![vec![U256::from(1), U256::from(0x100)]]),
+    })
+    .unwrap();
+    assert_eq!(fee_history["oldestBlock"], json!("0x100"));
+    assert_eq!(fee_history["baseFeePerGas"], json!(["0x0", "0x10"]));
+    assert_eq!(fee_history["reward"], json!([["0x1", "0x100"]]));
+    let logs_with_meta = LogsWithMeta {
+        logs: Vec::new(),
+        finalized_block: U64::zero(),
+    };
+    assert_eq!(
+        serde_json::to_value(logs_with_meta).unwrap()["finalizedBlock"],
+        json!("0x0")
+    );
+    let token = TokenInfo {
+        id: U64::from(10),
+        address: H160::zero(),
+        symbol: "DAI".to_string(),
+        decimals: 18,
+    };
+    assert_eq!(serde_json::to_value(token).unwrap()["id"], json!("0xa"));
+    let call_request = serde_json::to_value(CallRequest {
+        gas: Some(U256::from(0x5208)),
+        value: Some(U256::zero()),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(call_request["gas"], json!("0x5208"));
+    assert_eq!(call_request["gasPrice"], Value::Null);
+    assert_eq!(call_request["value"], json!("0x0"));
+
+    // Quantity fields of the re-exported `web3` types follow the same rule.
+    let transaction = transaction_from_tx_data(TxData {
+        block_hash: H256::zero(),
+        block_number: 256,
+        block_index: Some(0),
+        from: H160::zero(),
+        to: None,
+        nonce: 0,
+        tx_hash: H256::zero(),
+        value: U256::from(256),
+    });
+    let transaction = serde_json::to_value(transaction).unwrap();
+    assert_eq!(transaction["blockNumber"], json!("0x100"));
+    assert_eq!(transaction["nonce"], json!("0x0"));
+    assert_eq!(transaction["value"], json!("0x100"));
+    assert_eq!(transaction["gas"], json!("0x0"));
+    assert_eq!(transaction["gasPrice"], json!("0x0"));
+}
+
+#[test]
+/// Tests that the transaction nonce is serialized as a quantity, while the block nonce
+/// is serialized as 8 bytes of data.
+fn nonce_serialization() {
+    let transaction = transaction_from_tx_data(TxData {
+        block_hash: H256::zero(),
+        block_number: 1,
+        block_index: Some(0),
+        from: H160::zero(),
+        to: None,
+        nonce: 10,
+        tx_hash: H256::zero(),
+        value: U256::zero(),
+    });
+    let transaction = serde_json::to_value(transaction).unwrap();
+    assert_eq!(transaction["nonce"], json!("0xa"));
+
+    let mut block = BlockInfo::test_block(BlockNumber(1), Vec::new());
+    let block_json = serde_json::to_value(&block).unwrap();
+    assert_eq!(block_json["nonce"], json!("0x0000000000000000"));
+    if let BlockInfo::BlockWithHashes(block) = &mut block {
+        block.nonce = Some(H64::from_low_u64_be(10));
+    }
+    let block_json = serde_json::to_value(&block).unwrap();
+    assert_eq!(block_json["nonce"], json!("0x000000000000000a"));
+}
+
+#[test]
+/// Tests that data is serialized as even-length hex strings, preserving leading zero bytes.
+fn data_serialization() {
+    let data = |value: &[u8]| {
+        serialize_data(&Bytes(value.to_vec()), serde_json::value::Serializer).unwrap()
+    };
+    let mut bytes = vec![0u8; 31];
+    bytes[30] = 1;
+    assert_eq!(data(&bytes), json!(format!("0x{}01", "00".repeat(30))));
+    assert_eq!(data(&[]), json!("0x"));
+    assert_eq!(
+        serialize_data(&None::<Bytes>, serde_json::value::Serializer).unwrap(),
+        Value::Null
+    );
+
+    // The re-exported `web3` types follow the same format.
+    assert_eq!(
+        serde_json::to_value(Bytes(bytes.clone())).unwrap(),
+        data(&bytes)
+    );
+    let hash = H256::from_low_u64_be(1);
+    assert_eq!(serde_json::to_value(hash).unwrap(), data(hash.as_bytes()));
+    let address = H160::from_low_u64_be(1);
+    assert_eq!(
+        serde_json::to_value(address).unwrap(),
+        json!(format!("0x{}01", "00".repeat(19)))
+    );
+
+    // Byte array fields of the zkSync-specific types.
+    let call_request = serde_json::to_value(CallRequest {
+        to: address,
+        data: Some(Bytes(vec![0, 0, 0x0a])),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(call_request["to"], data(address.as_bytes()));
+    assert_eq!(call_request["data"], json!("0x00000a"));
+    let call_request = serde_json::to_value(CallRequest::default()).unwrap();
+    assert_eq!(call_request["data"], Value::Null);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+/// Tests that `data` and `topics` of all the logs are even-length hex strings.
+async fn logs_data_even_length() -> anyhow::Result<()> {
+    let fut = {
+        let (client, server) = local_client().await?;
+        let mut req = Map::new();
+        req.insert("fromBlock".to_string(), Value::String("0x1".to_string()));
+        req.insert("toBlock".to_string(), Value::String("pending".to_string()));
+        join(
+            client.call_method("eth_getLogs", Params::Array(vec![Value::Object(req)])),
+            server,
+        )
+    };
+    let logs = fut.await.0.unwrap();
+    let logs = logs.as_array().unwrap();
+    assert!(!logs.is_empty());
+
+    let is_even_length_hex = |value: &Value| {
+        let value = value.as_str().unwrap();
+        value.starts_with("0x") && value.len() % 2 == 0
+    };
+    for log in logs {
+        assert!(is_even_length_hex(&log["data"]), "{}", log);
+        for topic in log["topics"].as_array().unwrap() {
+            assert!(is_even_length_hex(topic), "{}", log);
+            assert_eq!(topic.as_str().unwrap().len(), 66);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+/// Tests that the logs index is updated by the new blocks and truncated by reverts.
+fn logs_index_updates() {
+    let log = |block_number: u32, topic: H256| Log {
+        address: H160::zero(),
+        topics: vec![topic],
+        data: Vec::new().into(),
+        block_hash: Some(H256::from_low_u64_be(block_number as u64)),
+        block_number: Some(block_number.into()),
+        transaction_hash: None,
+        transaction_index: None,
+        log_index: None,
+        transaction_log_index: None,
+        log_type: None,
+        removed: Some(false),
+    };
+    let hash = |block_number: u32| H256::from_low_u64_be(block_number as u64);
+    let all_logs = LogsFilter::new(Vec::new(), Vec::new());
+
+    let mut index = LogsIndex::new(3);
+    index.append_block(BlockNumber(1), hash(1), vec![log(1, H256::repeat_byte(1))]);
+    index.append_block(BlockNumber(2), hash(2), vec![log(2, H256::repeat_byte(2))]);
+    assert_eq!(index.last_block(), Some((BlockNumber(2), hash(2))));
+    assert_eq!(
+        index.logs(BlockNumber(1), BlockNumber(2), &all_logs).len(),
+        2
+    );
+
+    // Only the logs matching the filter are returned.
+    let filter = LogsFilter::new(Vec::new(), vec![H256::repeat_byte(2)]);
+    assert_eq!(
+        index.logs(BlockNumber(1), BlockNumber(2), &filter),
+        vec![log(2, H256::repeat_byte(2))]
+    );
+
+    // Already indexed blocks are ignored.
+    index.append_block(BlockNumber(2), hash(3), Vec::new());
+    assert_eq!(index.last_block(), Some((BlockNumber(2), hash(2))));
+
+    // Revert of the block 2 removes its logs.
+    index.truncate(BlockNumber(1));
+    assert_eq!(index.last_block(), Some((BlockNumber(1), hash(1))));
+    assert_eq!(
+        index.logs(BlockNumber(1), BlockNumber(2), &all_logs),
+        vec![log(1, H256::repeat_byte(1))]
+    );
+
+    // New blocks are appended after the revert, the oldest blocks are evicted.
+    for block_number in 2..=4 {
+        index.append_block(BlockNumber(block_number), hash(block_number), Vec::new());
+    }
+    assert_eq!(index.first_block(), Some(BlockNumber(2)));
+    assert_eq!(index.last_block(), Some((BlockNumber(4), hash(4))));
+
+    // A gap after the last indexed block restarts the index.
+    index.append_block(BlockNumber(10), hash(10), vec![log(10, H256::zero())]);
+    assert_eq!(index.first_block(), Some(BlockNumber(10)));
+    assert_eq!(
+        index.logs(BlockNumber(1), BlockNumber(10), &all_logs).len(),
+        1
+    );
+
+    // Disabled index stays empty.
+    let mut index = LogsIndex::new(0);
+    index.append_block(BlockNumber(1), hash(1), vec![log(1, H256::zero())]);
+    assert_eq!(index.last_block(), None);
+}
+
+/// Checks that all the methods accepting a block tag interpret `pending` according to the config.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn pending_tag_config() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let (last_finalized_block, last_saved_block, address) = {
+        let mut storage = cfg.pool.access_storage().await?;
+        let last_finalized_block = storage
+            .chain()
+            .block_schema()
+            .get_last_verified_confirmed_block()
+            .await?;
+        let last_saved_block = storage
+            .chain()
+            .block_schema()
+            .get_last_saved_block()
+            .await?;
+        // An account that has sent transactions, so that its nonce isn't zero.
+        let (_, accounts) = storage
+            .chain()
+            .state_schema()
+            .load_committed_state(None)
+            .await?;
+        let address = accounts
+            .values()
+            .find(|account| *account.nonce > 0)
+            .map(|account| account.address)
+            .expect("there is no account with transactions");
+        (last_finalized_block, last_saved_block, address)
+    };
+    assert!(last_finalized_block < last_saved_block);
+
+    let call = |config: Web3Config, method: &'static str, params: Vec<Value>| {
+        let (client, server) = local_client_with_config(cfg.pool.clone(), &config);
+        async move {
+            join(client.call_method(method, Params::Array(params)), server)
+                .await
+                .0
+                .unwrap()
+        }
+    };
+    // Returns the params of every method for the given block tag.
+    let method_params = |block: Value| {
+        let mut filter = Map::new();
+        filter.insert("fromBlock".to_string(), json!("0x1"));
+        filter.insert("toBlock".to_string(), block.clone());
+        vec![
+            ("eth_getBalance", vec![json!(H160::zero()), block.clone()]),
+            (
+                "eth_getTransactionCount",
+                vec![json!(address), block.clone()],
+            ),
+            ("eth_getBlockTransactionCountByNumber", vec![block.clone()]),
+            ("eth_getBlockByNumber", vec![block.clone(), json!(false)]),
+            ("eth_getLogs", vec![Value::Object(filter)]),
+            ("eth_feeHistory", vec![json!("0x1"), block]),
+        ]
+    };
+
+    for (includes_unconfirmed, expected_block) in
+        [(true, last_saved_block), (false, last_finalized_block)]
+    {
+        let config = Web3Config {
+            pending_includes_unconfirmed_blocks: includes_unconfirmed,
+            ..cfg.config.api.web3.clone()
+        };
+
+        let pending_block = call(
+            config.clone(),
+            "eth_getBlockByNumber",
+            vec![json!("pending"), json!(false)],
+        )
+        .await;
+        assert_eq!(
+            pending_block["number"],
+            json!(format!("{:#x}", expected_block.0))
+        );
+
+        let pending_params = method_params(json!("pending"));
+        let expected_params = method_params(json!(format!("{:#x}", expected_block.0)));
+        for ((method, pending_params), (_, expected_params)) in
+            pending_params.into_iter().zip(expected_params)
+        {
+            let pending_response = call(config.clone(), method, pending_params).await;
+            let expected_response = call(config.clone(), method, expected_params).await;
+            assert_eq!(
+                pending_response, expected_response,
+                "`{}` treats `pending` differently",
+                method
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Checks that `zks_getTokens` lists the registered tokens and picks up new ones after the cache invalidation.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn get_tokens() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let token_config = TokenConfig {
+        invalidate_token_cache_period_sec: 1,
+    };
+    let rpc_app = Web3RpcApp::new(cfg.pool.clone(), &cfg.config.api.web3, &token_config);
+    let get_tokens = || {
+        let mut io = IoHandler::new();
+        rpc_app.clone().extend(&mut io);
+        async move {
+            let (client, server) =
+                jsonrpc_core_client::transports::local::connect::<RawClient, _, _>(io);
+            let fut = join(client.call_method("zks_getTokens", Params::None), server);
+            let response = fut.await.0.unwrap();
+            Ok::<_, anyhow::Error>(serde_json::from_value::<Vec<TokenInfo>>(response)?)
+        }
+    };
+
+    let tokens = get_tokens().await?;
+    assert_eq!(
+        tokens[0],
+        TokenInfo {
+            id: U64::zero(),
+            address: H160::zero(),
+            symbol: "ETH".to_string(),
+            decimals: 18,
+        }
+    );
+
+    let new_token = Token::new(
+        TokenId(1000),
+        H160::from_low_u64_be(1000),
+        "WEB3TKN",
+        6,
+        TokenKind::ERC20,
+    );
+    {
+        let mut storage = cfg.pool.access_storage().await?;
+        storage
+            .tokens_schema()
+            .store_or_update_token(new_token.clone())
+            .await?;
+    }
+
+    // The list is served from the cache until it's invalidated.
+    assert_eq!(get_tokens().await?, tokens);
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    let tokens = get_tokens().await?;
+    assert!(tokens.contains(&TokenInfo::from(new_token)));
+    Ok(())
+}
+
+#[test]
+/// Tests that `totalDifficulty` is zero by default and omitted when it's disabled.
+fn total_difficulty_serialization() {
+    let block = BlockInfo::new_with_hashes(
+        H256::repeat_byte(1),
+        H256::zero(),
+        BlockNumber(1),
+        0,
+        vec![H256::repeat_byte(2)],
+    );
+
+    let value = serde_json::to_value(&block).unwrap();
+    assert_eq!(value["totalDifficulty"], json!("0x0"));
+    assert_eq!(value["difficulty"], json!("0x0"));
+    assert_eq!(serde_json::from_value::<BlockInfo>(value).unwrap(), block);
+
+    let block = block.without_total_difficulty();
+    let value = serde_json::to_value(&block).unwrap();
+    assert!(value.get("totalDifficulty").is_none());
+    assert_eq!(value["difficulty"], json!("0x0"));
+    assert_eq!(serde_json::from_value::<BlockInfo>(value).unwrap(), block);
+}
+
+#[test]
+/// Tests that the types re-exported from the `types` module keep their serialization format.
+fn types_serialization() {
+    assert_eq!(
+        serde_json::to_value(Web3BlockNumber::Number(U64::from(16))).unwrap(),
+        json!("0x10")
+    );
+    assert_eq!(
+        serde_json::to_value(Web3BlockNumber::Committed).unwrap(),
+        json!("committed")
+    );
+
+    let filter = Filter {
+        from_block: Some(Web3BlockNumber::Earliest),
+        to_block: Some(Web3BlockNumber::Number(U64::one())),
+        block_hash: None,
+        address: Some(ValueOrArray(vec![H160::repeat_byte(1)])),
+        topics: Some(vec![
+            Some(ValueOrArray(vec![
+                H256::repeat_byte(2),
+                H256::repeat_byte(3),
+            ])),
+            None,
+        ]),
+    };
+    let value = serde_json::to_value(&filter).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "fromBlock": "earliest",
+            "toBlock": "0x1",
+            "address": H160::repeat_byte(1),
+            "topics": [[H256::repeat_byte(2), H256::repeat_byte(3)], null],
+        })
+    );
+    assert_eq!(serde_json::from_value::<Filter>(value).unwrap(), filter);
+
+    let call_request = CallRequest {
+        to: H160::repeat_byte(1),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(call_request).unwrap(),
+        json!({
+            "from": null,
+            "to": H160::repeat_byte(1),
+            "gas": null,
+            "gasPrice": null,
+            "value": null,
+            "data": null,
+        })
+    );
+
+    let logs_with_meta = LogsWithMeta {
+        logs: Vec::new(),
+        finalized_block: U64::from(5),
+    };
+    assert_eq!(
+        serde_json::to_value(logs_with_meta).unwrap(),
+        json!({ "logs": [], "finalizedBlock": "0x5" })
+    );
+
+    let token = TokenInfo {
+        id: U64::one(),
+        address: H160::repeat_byte(1),
+        symbol: "TKN".to_string(),
+        decimals: 18,
+    };
+    assert_eq!(
+        serde_json::to_value(token).unwrap(),
+        json!({
+            "id": "0x1",
+            "address": H160::repeat_byte(1),
+            "symbol": "TKN",
+            "decimals": 18,
+        })
+    );
+}
+
+/// Checks that logs of a non-finalized block carry the hash returned for the block itself.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn logs_block_hash() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let block_number = {
+        let mut storage = cfg.pool.access_storage().await?;
+        let last_finalized_block = storage
+            .chain()
+            .block_schema()
+            .get_last_verified_confirmed_block()
+            .await?;
+        let last_committed_block = storage
+            .chain()
+            .block_schema()
+            .get_last_committed_confirmed_block()
+            .await?;
+        assert!(last_finalized_block < last_committed_block);
+        last_finalized_block + 1
+    };
+    let block_number = format!("{:#x}", block_number.0);
+
+    let mut req = Map::new();
+    req.insert("fromBlock".to_string(), json!(block_number));
+    req.insert("toBlock".to_string(), json!(block_number));
+    let fut = {
+        let (client, server) = local_client().await?;
+        join(
+            client.call_method("eth_getLogs", Params::Array(vec![Value::Object(req)])),
+            server,
+        )
+    };
+    let logs: Vec<Log> = serde_json::from_value(fut.await.0.unwrap())?;
+    assert!(!logs.is_empty());
+
+    for include_txs in [false, true] {
+        let fut = {
+            let (client, server) = local_client().await?;
+            join(
+                client.call_method(
+                    "eth_getBlockByNumber",
+                    Params::Array(vec![json!(block_number), json!(include_txs)]),
+                ),
+                server,
+            )
+        };
+        let block = fut.await.0.unwrap();
+        let block_hash: H256 = serde_json::from_value(block["hash"].clone())?;
+        for log in &logs {
+            assert_eq!(log.block_hash, Some(block_hash));
+        }
+    }
+    Ok(())
+}
+
+#[test]
+/// Tests that the details of internal errors are sent only if they're revealed by the config.
+fn internal_errors_reveal() {
+    let err = internal_error(anyhow::anyhow!("connection refused"));
+
+    let production_err = sanitize_error(err.clone(), false);
+    assert_eq!(production_err, Error::internal_error());
+
+    let dev_err = sanitize_error(err, true);
+    assert_eq!(dev_err.code, ErrorCode::InternalError);
+    assert_eq!(dev_err.message, "Internal error: connection refused");
+
+    // Other errors are sent as is in both modes.
+    let invalid_params = Error::invalid_params("Invalid block");
+    assert_eq!(
+        sanitize_error(invalid_params.clone(), false),
+        invalid_params
+    );
+    assert_eq!(sanitize_error(invalid_params.clone(), true), invalid_params);
+}
+
+/// Checks that `zks_getTransactionDetails` reports transactions pending beyond the threshold as stuck.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn get_transaction_details() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let stuck_tx_threshold = cfg.config.api.web3.stuck_tx_threshold();
+
+    let txs = TestServerConfig::gen_zk_txs(2_u64).txs;
+    let (stuck_tx, fresh_tx) = (txs[0].0.clone(), txs[1].0.clone());
+    let (stuck_tx_hash, fresh_tx_hash) = (
+        H256::from_slice(stuck_tx.hash().as_ref()),
+        H256::from_slice(fresh_tx.hash().as_ref()),
+    );
+    let executed_tx_hash = {
+        let mut storage = cfg.pool.access_storage().await?;
+        let received_at = Utc::now() - chrono::Duration::from_std(stuck_tx_threshold * 2)?;
+        for (tx, created_at) in [(stuck_tx, received_at), (fresh_tx, Utc::now())] {
+            storage
+                .chain()
+                .mempool_schema()
+                .insert_tx(&SignedZkSyncTx {
+                    tx,
+                    eth_sign_data: None,
+                    created_at,
+                })
+                .await?;
+        }
+
+        let block = storage
+            .chain()
+            .block_schema()
+            .get_block(BlockNumber(1))
+            .await?
+            .unwrap();
+        match &block.block_transactions[0] {
+            ExecutedOperations::Tx(tx) => H256::from_slice(tx.signed_tx.tx.hash().as_ref()),
+            ExecutedOperations::PriorityOp(op) => {
+                H256::from_slice(op.priority_op.tx_hash().as_ref())
+            }
+        }
+    };
+
+    let get_details = |hash: H256| async move {
+        let (client, server) = local_client().await?;
+        let fut = join(
+            client.call_method(
+                "zks_getTransactionDetails",
+                Params::Array(vec![serde_json::to_value(hash)?]),
+            ),
+            server,
+        );
+        let response = fut.await.0.unwrap();
+        Ok::<_, anyhow::Error>(serde_json::from_value::<Option<TransactionDetails>>(
+            response,
+        )?)
+    };
+
+    let details = get_details(stuck_tx_hash).await?.unwrap();
+    assert!(details.pending);
+    assert!(details.may_be_stuck);
+    assert!(details.received_at.is_some());
+    assert!(details.transaction.is_none());
+
+    let details = get_details(fresh_tx_hash).await?.unwrap();
+    assert!(details.pending);
+    assert!(!details.may_be_stuck);
+
+    let details = get_details(executed_tx_hash).await?.unwrap();
+    assert!(!details.pending);
+    assert!(!details.may_be_stuck);
+    assert_eq!(details.transaction.unwrap().hash, executed_tx_hash);
+
+    assert!(get_details(H256::repeat_byte(0xff)).await?.is_none());
+    Ok(())
+}
+
+#[test]
+/// Tests boundary block numbers serialization and conversion into storage block numbers.
+fn boundary_block_numbers() {
+    for (number, serialized) in [
+        (U64::zero(), "0x0"),
+        (U64::from(u32::MAX), "0xffffffff"),
+        (U64::from(u32::MAX as u64 + 1), "0x100000000"),
+        (U64::MAX, "0xffffffffffffffff"),
+    ] {
+        let block_number = Web3BlockNumber::Number(number);
+        let value = serde_json::to_value(block_number).unwrap();
+        assert_eq!(value, json!(serialized));
+        assert_eq!(
+            serde_json::from_value::<Web3BlockNumber>(value).unwrap(),
+            block_number
+        );
+    }
+
+    assert_eq!(
+        storage_block_number(U64::zero(), "block").unwrap(),
+        BlockNumber(0)
+    );
+    assert_eq!(
+        storage_block_number(U64::from(u32::MAX), "block").unwrap(),
+        BlockNumber(u32::MAX)
+    );
+    for number in [U64::from(u32::MAX as u64 + 1), U64::MAX] {
+        let err = storage_block_number(number, "block").unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidParams);
+        assert!(err.message.contains("`block`"), "{}", err.message);
+    }
+}
+
+/// Returns the digits of a `0x`-prefixed hex string.
+fn strict_hex_digits<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    match value.strip_prefix("0x") {
+        Some(digits) if digits.chars().all(|digit| digit.is_ascii_hexdigit()) => {
+            Ok(digits.to_string())
+        }
+        _ => Err(de::Error::custom(format!(
+            "`{}` is not a hex string",
+            value
+        ))),
+    }
+}
+
+/// Quantity encoded as required by EIP-1474: hex without leading zeros.
+#[derive(Debug)]
+struct StrictQuantity;
+
+impl<'de> Deserialize<'de> for StrictQuantity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let digits = strict_hex_digits(deserializer)?;
+        if digits.is_empty() || (digits.len() > 1 && digits.starts_with('0')) {
+            return Err(de::Error::custom(format!(
+                "`0x{}` is not a quantity",
+                digits
+            )));
+        }
+        Ok(Self)
+    }
+}
+
+/// Data encoded as required by EIP-1474: two hex digits per byte, `N` bytes unless `N` is zero.
+#[derive(Debug)]
+struct StrictData<const N: usize>;
+
+impl<'de, const N: usize> Deserialize<'de> for StrictData<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let digits = strict_hex_digits(deserializer)?;
+        if digits.len() % 2 != 0 || (N != 0 && digits.len() != 2 * N) {
+            return Err(de::Error::custom(format!(
+                "`0x{}` is not {} bytes of data",
+                digits, N
+            )));
+        }
+        Ok(Self)
+    }
+}
+
+// The structures below follow the EIP-1474 objects and are independent of the `web3` types
+// the server uses. Nullable fields must still be present, unknown fields are rejected.
+// The fields are only validated, so they are never read.
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct StrictTransaction {
+    #[serde(deserialize_with = "Option::deserialize")]
+    block_hash: Option<StrictData<32>>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    block_number: Option<StrictQuantity>,
+    from: StrictData<20>,
+    gas: StrictQuantity,
+    gas_price: StrictQuantity,
+    hash: StrictData<32>,
+    input: StrictData<0>,
+    nonce: StrictQuantity,
+    #[serde(deserialize_with = "Option::deserialize")]
+    to: Option<StrictData<20>>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    transaction_index: Option<StrictQuantity>,
+    value: StrictQuantity,
+    // Synthesized transactions are not signed on L1 and are not typed.
+    #[serde(default)]
+    v: Option<StrictQuantity>,
+    #[serde(default)]
+    r: Option<StrictQuantity>,
+    #[serde(default)]
+    s: Option<StrictQuantity>,
+    #[serde(default)]
+    raw: Option<StrictData<0>>,
+    #[serde(default, rename = "type")]
+    transaction_type: Option<StrictQuantity>,
+    #[serde(default)]
+    access_list: Option<Vec<Value>>,
+    #[serde(default)]
+    max_fee_per_gas: Option<StrictQuantity>,
+    #[serde(default)]
+    max_priority_fee_per_gas: Option<StrictQuantity>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct StrictLog {
+    address: StrictData<20>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    block_hash: Option<StrictData<32>>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    block_number: Option<StrictQuantity>,
+    data: StrictData<0>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    log_index: Option<StrictQuantity>,
+    removed: bool,
+    topics: Vec<StrictData<32>>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    transaction_hash: Option<StrictData<32>>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    transaction_index: Option<StrictQuantity>,
+    // Non-standard fields returned by OpenEthereum as well.
+    #[serde(default)]
+    transaction_log_index: Option<StrictQuantity>,
+    #[serde(default)]
+    log_type: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct StrictReceipt {
+    #[serde(deserialize_with = "Option::deserialize")]
+    block_hash: Option<StrictData<32>>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    block_number: Option<StrictQuantity>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    contract_address: Option<StrictData<20>>,
+    cumulative_gas_used: StrictQuantity,
+    from: StrictData<20>,
+    gas_used: StrictQuantity,
+    logs: Vec<StrictLog>,
+    logs_bloom: StrictData<256>,
+    #[serde(deserialize_with = "Option::deserialize")]
+    to: Option<StrictData<20>>,
+    transaction_hash: StrictData<32>,
+    transaction_index: StrictQuantity,
+    // Either `root` or `status` is returned, depending on the chain version.
+    #[serde(default)]
+    root: Option<StrictData<32>>,
+    #[serde(default)]
+    status: Option<StrictQuantity>,
+    #[serde(default, rename = "type")]
+    transaction_type: Option<StrictQuantity>,
+    #[serde(default)]
+    effective_gas_price: Option<StrictQuantity>,
+}
+
+/// Checks that synthesized transactions and receipts follow the EIP-1474 schema, so that
+/// they can be parsed by the Ethereum clients other than the `web3` crate used by the server.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn transactions_parse_back() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+
+    let fixtures = {
+        let mut storage = cfg.pool.access_storage().await?;
+        let last_block = storage
+            .chain()
+            .block_schema()
+            .get_last_committed_confirmed_block()
+            .await?;
+        let receipts = storage
+            .chain()
+            .operations_ext_schema()
+            .web3_receipts(BlockNumber(1), last_block)
+            .await?;
+        let find = |name: &str, predicate: &dyn Fn(&Web3TxReceipt) -> bool| {
+            let receipt = receipts
+                .iter()
+                .find(|receipt| predicate(receipt))
+                .unwrap_or_else(|| panic!("there is no {} in the test data", name));
+            (name.to_string(), H256::from_slice(&receipt.tx_hash))
+        };
+        vec![
+            find("transfer", &|receipt| {
+                receipt.success && receipt.operation["type"] == json!("Transfer")
+            }),
+            find("withdrawal", &|receipt| {
+                receipt.success && receipt.operation["type"] == json!("Withdraw")
+            }),
+            find("failed transaction", &|receipt| !receipt.success),
+        ]
+    };
+
+    for (name, tx_hash) in fixtures {
+        let fut = {
+            let (client, server) = local_client().await?;
+            let params = || Params::Array(vec![serde_json::to_value(tx_hash).unwrap()]);
+            let transaction = client.call_method("eth_getTransactionByHash", params());
+            let receipt = client.call_method("eth_getTransactionReceipt", params());
+            join(join(transaction, receipt), server)
+        };
+        let ((transaction, receipt), _) = fut.await;
+        let (transaction, receipt) = (transaction.unwrap(), receipt.unwrap());
+
+        serde_json::from_value::<StrictTransaction>(transaction.clone()).unwrap_or_else(|err| {
+            panic!(
+                "{} transaction {} can't be parsed: {}",
+                name, transaction, err
+            )
+        });
+        serde_json::from_value::<StrictReceipt>(receipt.clone())
+            .unwrap_or_else(|err| panic!("{} receipt {} can't be parsed: {}", name, receipt, err));
+    }
+    Ok(())
+}
+
+/// Tests `zks_getTokenBalance` method
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn get_token_balance() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+
+    let (address, held_token, held_balance, unheld_token) = {
+        let mut storage = cfg.pool.access_storage().await?;
+        let address = storage
+            .chain()
+            .account_schema()
+            .account_address_by_id(AccountId(3))
+            .await?
+            .unwrap();
+        let last_block = storage
+            .chain()
+            .block_schema()
+            .get_last_verified_confirmed_block()
+            .await?;
+        let held_token = storage
+            .tokens_schema()
+            .get_token(TokenId(1).into())
+            .await?
+            .unwrap();
+        let held_balance = storage
+            .chain()
+            .account_schema()
+            .get_account_balance_for_block(address, last_block, held_token.id)
+            .await?;
+        assert!(held_balance > BigUint::from(0u32));
+
+        let mut unheld_token = None;
+        for token in storage.tokens_schema().load_tokens().await?.into_values() {
+            let balance = storage
+                .chain()
+                .account_schema()
+                .get_account_balance_for_block(address, last_block, token.id)
+                .await?;
+            if balance == BigUint::from(0u32) {
+                unheld_token = Some(token);
+                break;
+            }
+        }
+        (
+            address,
+            held_token,
+            u256_from_biguint(held_balance),
+            unheld_token.expect("the account holds all the tokens"),
+        )
+    };
+
+    let get_token_balance = |token: H160| async move {
+        let (client, server) = local_client().await?;
+        let fut = join(
+            client.call_method(
+                "zks_getTokenBalance",
+                Params::Array(vec![json!(address), json!(token), json!("latest")]),
+            ),
+            server,
+        );
+        Ok::<_, anyhow::Error>(fut.await.0)
+    };
+
+    let balance = get_token_balance(held_token.address).await?.unwrap();
+    assert_eq!(serde_json::from_value::<U256>(balance)?, held_balance);
+
+    let balance = get_token_balance(unheld_token.address).await?.unwrap();
+    assert_eq!(balance, json!("0x0"));
+
+    let error = get_token_balance(H160::repeat_byte(0xff))
+        .await?
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        RpcError::JsonRpcError(Error {
+            code: ErrorCode::InvalidParams,
+            ..
+        })
+    ));
+    Ok(())
+}
+
+/// Tests `zks_getTransactionLogs` method
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn get_transaction_logs() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+
+    let (transfer_hash, failed_tx_hash) = {
+        let mut storage = cfg.pool.access_storage().await?;
+        let last_block = storage
+            .chain()
+            .block_schema()
+            .get_last_committed_confirmed_block()
+            .await?;
+        let receipts = storage
+            .chain()
+            .operations_ext_schema()
+            .web3_receipts(BlockNumber(1), last_block)
+            .await?;
+        let transfer = receipts
+            .iter()
+            .find(|receipt| receipt.success && receipt.operation["type"] == json!("Transfer"))
+            .unwrap();
+        let failed_tx = receipts.iter().find(|receipt| !receipt.success).unwrap();
+        (
+            H256::from_slice(&transfer.tx_hash),
+            H256::from_slice(&failed_tx.tx_hash),
+        )
+    };
+
+    let get_logs = |hash: H256| async move {
+        let (client, server) = local_client().await?;
+        let params = || Params::Array(vec![serde_json::to_value(hash).unwrap()]);
+        let fut = join(
+            join(
+                client.call_method("zks_getTransactionLogs", params()),
+                client.call_method("eth_getTransactionReceipt", params()),
+            ),
+            server,
+        );
+        let ((logs, receipt), _) = fut.await;
+        Ok::<_, anyhow::Error>((logs.unwrap(), receipt.unwrap()))
+    };
+
+    // The logs are the same as in the receipt.
+    let (logs, receipt) = get_logs(transfer_hash).await?;
+    let logs: Vec<Log> = serde_json::from_value(logs)?;
+    let receipt: TransactionReceipt = serde_json::from_value(receipt)?;
+    assert!(!logs.is_empty());
+    assert!(logs
+        .iter()
+        .all(|log| log.transaction_hash == Some(transfer_hash)));
+    assert_eq!(logs, receipt.logs);
+
+    let (logs, _) = get_logs(failed_tx_hash).await?;
+    assert_eq!(logs, json!([]));
+
+    let (logs, receipt) = get_logs(H256::repeat_byte(0xff)).await?;
+    assert_eq!(logs, Value::Null);
+    assert_eq!(receipt, Value::Null);
+    Ok(())
+}
+
+/// Checks that out-of-range and malformed quantities are rejected with "invalid params" errors
+/// naming the offending parameter.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn invalid_quantity_params() -> anyhow::Result<()> {
+    let cases = [
+        (
+            "eth_getBlockByNumber",
+            json!(["0x100000000", false]),
+            "`block`",
+        ),
+        (
+            "eth_getBlockByNumber",
+            json!(["0x10000000000000000", false]),
+            "`block`",
+        ),
+        ("eth_getBlockByNumber", json!(["0xzz", false]), "`block`"),
+        ("eth_getBalance", json!([H160::zero(), "0xzz"]), "`block`"),
+        ("eth_feeHistory", json!(["0xzz", "latest"]), "`blockCount`"),
+        ("eth_feeHistory", json!([-1, "latest"]), "`blockCount`"),
+        ("eth_feeHistory", json!(["0x1", "0xzz"]), "`newestBlock`"),
+        (
+            "eth_getLogs",
+            json!([{ "fromBlock": "0x0", "toBlock": "0x100000000" }]),
+            "`toBlock`",
+        ),
+        (
+            "eth_getLogs",
+            json!([{ "fromBlock": "0xzz" }]),
+            "`fromBlock`",
+        ),
+    ];
+
+    for (method, params, expected) in cases {
+        let fut = {
+            let (client, server) = local_client().await?;
+            let params = serde_json::from_value(params)?;
+            join(client.call_method(method, params), server)
+        };
+        let error = fut.await.0.unwrap_err();
+        match error {
+            RpcError::JsonRpcError(error) => {
+                assert_eq!(error.code, ErrorCode::InvalidParams, "{}", expected);
+                assert!(
+                    error.message.contains(expected),
+                    "{} is not named in: {}",
+                    expected,
+                    error.message
+                );
+            }
+            error => panic!("Unexpected error: {}", error),
+        }
+    }
+    Ok(())
+}
+
+/// Checks the invariants that must hold for consecutive blocks returned by the API, so that
+/// filling the placeholder block fields with real values can't silently break them.
+/// `baseFeePerGas` is unsigned, so it's non-negative by construction.
+fn check_block_invariants(blocks: &[BlockInfo]) -> Result<(), String> {
+    let headers = blocks.iter().map(|block| match block {
+        BlockInfo::BlockWithHashes(block) => (
+            block.number,
+            block.hash,
+            block.parent_hash,
+            block.state_root,
+            block.gas_used,
+            block.gas_limit,
+            block.timestamp,
+        ),
+        BlockInfo::BlockWithTxs(block) => (
+            block.number,
+            block.hash,
+            block.parent_hash,
+            block.state_root,
+            block.gas_used,
+            block.gas_limit,
+            block.timestamp,
+        ),
+    });
+
+    let mut parent = None;
+    for (number, hash, parent_hash, state_root, gas_used, gas_limit, timestamp) in headers {
+        if gas_used > gas_limit {
+            return Err(format!(
+                "Block {:?}: gasUsed {} exceeds gasLimit {}",
+                number, gas_used, gas_limit
+            ));
+        }
+        if let Some((parent_hash_expected, parent_state_root, parent_timestamp)) = parent {
+            if Some(parent_hash) != parent_hash_expected {
+                return Err(format!("Block {:?}: parentHash doesn't match", number));
+            }
+            if Some(parent_hash) == hash || parent_state_root == state_root {
+                return Err(format!(
+                    "Block {:?}: roots are the same as the parent's",
+                    number
+                ));
+            }
+            if timestamp < parent_timestamp {
+                return Err(format!("Block {:?}: timestamp is not monotonic", number));
+            }
+        }
+        parent = Some((hash, state_root, timestamp));
+    }
+    Ok(())
+}
+
+/// Checks that the blocks returned by `eth_getBlockByNumber` are consistent.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn block_invariants() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let last_block = {
+        let mut storage = cfg.pool.access_storage().await?;
+        storage
+            .chain()
+            .block_schema()
+            .get_last_saved_block()
+            .await?
+    };
+
+    let mut blocks = Vec::new();
+    for number in 1..=last_block.0 {
+        let fut = {
+            let (client, server) = local_client().await?;
+            join(
+                client.call_method(
+                    "eth_getBlockByNumber",
+                    Params::Array(vec![json!(U64::from(number)), json!(false)]),
+                ),
+                server,
+            )
+        };
+        let block = fut.await.0.unwrap();
+        blocks.push(serde_json::from_value::<BlockInfo>(block)?);
+    }
+    check_block_invariants(&blocks).map_err(anyhow::Error::msg)?;
+    Ok(())
+}
+
+#[test]
+/// Tests that inconsistent blocks fail the invariant check.
+fn inconsistent_blocks() {
+    let first_block = BlockInfo::new_with_hashes(
+        H256::repeat_byte(1),
+        H256::zero(),
+        BlockNumber(1),
+        10,
+        Vec::new(),
+    );
+    let second_block = |parent_hash, timestamp| {
+        BlockInfo::new_with_hashes(
+            H256::repeat_byte(2),
+            parent_hash,
+            BlockNumber(2),
+            timestamp,
+            Vec::new(),
+        )
+    };
+
+    let valid_blocks = [first_block.clone(), second_block(H256::repeat_byte(1), 10)];
+    assert_eq!(check_block_invariants(&valid_blocks), Ok(()));
+
+    let mut gas_overflow = second_block(H256::repeat_byte(1), 10);
+    if let BlockInfo::BlockWithHashes(block) = &mut gas_overflow {
+        block.gas_used = block.gas_limit + 1;
+    }
+    for blocks in [
+        [first_block.clone(), gas_overflow],
+        [first_block.clone(), second_block(H256::repeat_byte(3), 10)],
+        [first_block.clone(), second_block(H256::repeat_byte(1), 9)],
+        [first_block.clone(), first_block.clone()],
+    ] {
+        assert!(check_block_invariants(&blocks).is_err(), "{:?}", blocks);
+    }
+}
+
+#[test]
+/// Tests that the test blocks are fully populated and pass the invariant check.
+fn test_blocks() {
+    let blocks: Vec<_> = (0..5)
+        .map(|number| BlockInfo::test_block(BlockNumber(number), vec![H256::repeat_byte(1)]))
+        .collect();
+    check_block_invariants(&blocks).unwrap();
+
+    for block in &blocks {
+        let block = serde_json::to_value(block).unwrap();
+        for field in [
+            "author",
+            "baseFeePerGas",
+            "gasUsed",
+            "gasLimit",
+            "size",
+            "stateRoot",
+        ] {
+            assert!(!block[field].is_null(), "{}: {}", field, block);
+        }
+    }
+}
+
+/// Tests that `eth_getBalance` resolves the `finalized` and `safe` tags to the last finalized block,
+/// which may lag behind the last committed one.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn get_balance_finalized() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let address = H160::from_str("09d1ef5f45cfa30225edff40cebf657b4226b27b").unwrap();
+
+    let (finalized_balance, committed_balance) = {
+        let mut storage = cfg.pool.access_storage().await?;
+        let finalized_block = storage
+            .chain()
+            .block_schema()
+            .get_last_verified_confirmed_block()
+            .await?;
+        let committed_block = storage
+            .chain()
+            .block_schema()
+            .get_last_committed_confirmed_block()
+            .await?;
+        assert!(finalized_block < committed_block);
+        let finalized_balance = storage
+            .chain()
+            .account_schema()
+            .get_account_balance_for_block(address, finalized_block, TokenId(0))
+            .await?;
+        let committed_balance = storage
+            .chain()
+            .account_schema()
+            .get_account_balance_for_block(address, committed_block, TokenId(0))
+            .await?;
+        (
+            u256_from_biguint(finalized_balance),
+            u256_from_biguint(committed_balance),
+        )
+    };
+
+    for (tag, expected) in [
+        ("finalized", finalized_balance),
+        ("safe", finalized_balance),
+        ("latest", finalized_balance),
+        ("committed", committed_balance),
+    ] {
+        let fut = {
+            let (client, server) = local_client().await?;
+            join(
+                client.call_method(
+                    "eth_getBalance",
+                    Params::Array(vec![json!(address), json!(tag)]),
+                ),
+                server,
+            )
+        };
+        let balance = fut.await.0.unwrap();
+        assert_eq!(
+            serde_json::from_value::<U256>(balance)?,
+            expected,
+            "{}",
+            tag
+        );
+    }
+    Ok(())
+}
+
+/// Tests that `eth_getTransactionCount` returns the account nonce as of the block the tag resolves to.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn get_transaction_count() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let address = H160::from_str("09d1ef5f45cfa30225edff40cebf657b4226b27b").unwrap();
+
+    let (finalized_nonce, committed_nonce) = {
+        let mut storage = cfg.pool.access_storage().await?;
+        let finalized_block = storage
+            .chain()
+            .block_schema()
+            .get_last_verified_confirmed_block()
+            .await?;
+        let committed_block = storage
+            .chain()
+            .block_schema()
+            .get_last_committed_confirmed_block()
+            .await?;
+        let finalized_nonce = storage
+            .chain()
+            .account_schema()
+            .get_account_nonce_for_block(address, finalized_block)
+            .await?;
+        let committed_nonce = storage
+            .chain()
+            .account_schema()
+            .get_account_nonce_for_block(address, committed_block)
+            .await?;
+        (U256::from(*finalized_nonce), U256::from(*committed_nonce))
+    };
+    assert!(finalized_nonce <= committed_nonce);
+
+    for (block, expected) in [
+        (json!("finalized"), finalized_nonce),
+        (json!("latest"), finalized_nonce),
+        (json!("committed"), committed_nonce),
+    ] {
+        let fut = {
+            let (client, server) = local_client().await?;
+            join(
+                client.call_method(
+                    "eth_getTransactionCount",
+                    Params::Array(vec![json!(address), block.clone()]),
+                ),
+                server,
+            )
+        };
+        let nonce = fut.await.0.unwrap();
+        assert_eq!(
+            serde_json::from_value::<U256>(nonce)?,
+            expected,
+            "{}",
+            block
+        );
+    }
+
+    // Unknown accounts have a zero nonce.
+    let fut = {
+        let (client, server) = local_client().await?;
+        join(
+            client.call_method(
+                "eth_getTransactionCount",
+                Params::Array(vec![json!(H160::random())]),
+            ),
+            server,
+        )
+    };
+    let nonce = fut.await.0.unwrap();
+    assert_eq!(serde_json::from_value::<U256>(nonce)?, U256::zero());
+    Ok(())
+}
+
+/// Tests that only JSON-RPC 2.0 calls are accepted and every response carries `jsonrpc: "2.0"`.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn jsonrpc_version() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let rpc_app = Web3RpcApp::new(cfg.pool, &cfg.config.api.web3, &cfg.config.api.token_config);
+    let mut io = MetaIoHandler::<(), _>::with_middleware(JsonRpcVersionMiddleware);
+    rpc_app.extend(&mut io);
+
+    let handle = |request: Value| {
+        let response = io.handle_request(&request.to_string(), ());
+        async move {
+            let response = response.await.expect("no response to the call");
+            serde_json::from_str::<Value>(&response).unwrap()
+        }
+    };
+    let assert_invalid_request = |response: &Value, id: Value| {
+        assert_eq!(response["jsonrpc"], json!("2.0"), "{}", response);
+        assert_eq!(response["error"]["code"], json!(-32600), "{}", response);
+        assert_eq!(response["id"], id, "{}", response);
+    };
+
+    let response = handle(json!({ "jsonrpc": "1.0", "method": "net_version", "id": 1 })).await;
+    assert_invalid_request(&response, json!(1));
+
+    let response = handle(json!({ "method": "net_version", "params": [], "id": 2 })).await;
+    assert_invalid_request(&response, json!(2));
+    assert_eq!(
+        response["error"]["message"],
+        json!("Only JSON-RPC 2.0 requests are supported")
+    );
+
+    // Well-formed 2.0 calls that are invalid for other reasons get the standard error.
+    for request in [
+        json!({ "jsonrpc": "2.0", "method": 42, "id": 8 }),
+        json!({ "jsonrpc": "2.0", "params": [], "id": 8 }),
+    ] {
+        let response = handle(request).await;
+        assert_invalid_request(&response, json!(8));
+        assert_eq!(
+            response["error"]["message"],
+            json!(Error::invalid_request().message)
+        );
+    }
+
+    let response = handle(json!({ "jsonrpc": "2.0", "method": "net_version", "id": 3 })).await;
+    assert_eq!(response["jsonrpc"], json!("2.0"));
+    assert!(response["result"].is_string());
+
+    let response = handle(json!({
+        "jsonrpc": "2.0",
+        "method": "eth_getBlockByNumber",
+        "params": ["0xzz", false],
+        "id": 4
+    }))
+    .await;
+    assert_eq!(response["jsonrpc"], json!("2.0"));
+    assert_eq!(response["error"]["code"], json!(-32602));
+
+    let response = handle(json!([
+        { "jsonrpc": "2.0", "method": "net_version", "id": 5 },
+        { "jsonrpc": "1.0", "method": "net_version", "id": 6 },
+        { "jsonrpc": "2.0", "method": "unknown_method", "id": 7 },
+    ]))
+    .await;
+    let responses = response.as_array().unwrap();
+    assert_eq!(responses.len(), 3);
+    assert!(responses
+        .iter()
+        .all(|response| response["jsonrpc"] == json!("2.0")));
+    assert_invalid_request(&responses[1], json!(6));
+    Ok(())
+}
+
+/// Tests that the resolution of block tags is logged at the trace level.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn block_tag_resolution_logging() -> anyhow::Result<()> {
+    #[derive(Clone, Default)]
+    struct LogsBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for LogsBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let last_verified_block = {
+        let mut storage = cfg.pool.access_storage().await?;
+        storage
+            .chain()
+            .block_schema()
+            .get_last_verified_confirmed_block()
+            .await?
+    };
+
+    let logs = LogsBuffer::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    // The calls are handled within the test task, so the thread-local subscriber catches their logs.
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let fut = {
+        let (client, server) = local_client().await?;
+        join(
+            client.call_method(
+                "eth_getBalance",
+                Params::Array(vec![json!(H160::zero()), json!("latest")]),
+            ),
+            server,
+        )
+    };
+    fut.await.0.unwrap();
+
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone())?;
+    let expected = format!(
+        "Block tag Some(Latest) of `block` is resolved to {:?}",
+        Some(last_verified_block)
+    );
+    assert!(logs.contains(&expected), "{}", logs);
+    Ok(())
+}
+
+/// Tests that reverted and unsupported `eth_call`s return the reason ABI-encoded as `Error(string)`
+/// in the error data.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn call_revert_reason() -> anyhow::Result<()> {
+    let nft_factory_address = H160::from_str(NFT_FACTORY_ADDRESS).unwrap();
+    let nonexistent_token_id = ethabi::encode(&[Token::Uint(U256::from(u32::MAX) + 1)]);
+    let zero_address = ethabi::encode(&[Token::Address(H160::zero())]);
+
+    for (to, selector, params, reason) in [
+        (
+            nft_factory_address,
+            "6352211e",
+            nonexistent_token_id,
+            "ERC721: owner query for nonexistent token",
+        ),
+        (
+            nft_factory_address,
+            "70a08231",
+            zero_address,
+            "ERC721: balance query for the zero address",
+        ),
+        (
+            nft_factory_address,
+            "deadbeef",
+            Vec::new(),
+            "function selector is not supported",
+        ),
+        // `ownerOf` with a truncated token id.
+        (
+            nft_factory_address,
+            "6352211e",
+            vec![1, 2, 3],
+            "invalid input for function `ownerOf`",
+        ),
+        (
+            H160::repeat_byte(0xfe),
+            "6352211e",
+            Vec::new(),
+            "call to an unsupported contract",
+        ),
+        (
+            nft_factory_address,
+            "",
+            vec![0x63],
+            "call data is shorter than a function selector",
+        ),
+    ] {
+        let fut = {
+            let (client, server) = local_client().await?;
+            let data = format!("0x{}{}", selector, hex::encode(params));
+            let req = json!({ "to": to, "data": data });
+            join(
+                client.call_method("eth_call", Params::Array(vec![req])),
+                server,
+            )
+        };
+        let error = match fut.await.0.unwrap_err() {
+            RpcError::JsonRpcError(error) => error,
+            error => panic!("Unexpected error: {}", error),
+        };
+        assert_eq!(error.code, ErrorCode::ServerError(3));
+        assert_eq!(error.message, format!("execution reverted: {}", reason));
+
+        let data = serde_json::from_value::<String>(error.data.unwrap())?;
+        let data = hex::decode(data.strip_prefix("0x").unwrap())?;
+        assert_eq!(data[..4], [0x08, 0xc3, 0x79, 0xa0]);
+        let decoded = ethabi::decode(&[ParamType::String], &data[4..])?;
+        assert_eq!(decoded, vec![Token::String(reason.to_string())]);
+    }
+    Ok(())
+}
+
+/// Tests validation of `rewardPercentiles` of `eth_feeHistory` method
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn fee_history_reward_percentiles() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+    let config = Web3Config {
+        max_fee_history_reward_percentiles: 3,
+        ..cfg.config.api.web3.clone()
+    };
+
+    let fee_history = |percentiles: Vec<f64>| {
+        let (client, server) = local_client_with_config(cfg.pool.clone(), &config);
+        async move {
+            let fut = join(
+                client.call_method(
+                    "eth_feeHistory",
+                    Params::Array(vec![json!("0x2"), json!("latest"), json!(percentiles)]),
+                ),
+                server,
+            );
+            fut.await.0
+        }
+    };
+
+    let fee_history_result = fee_history(vec![0.0, 50.0, 100.0]).await.unwrap();
+    let fee_history_result = serde_json::from_value::<FeeHistory>(fee_history_result)?;
+    assert_eq!(
+        fee_history_result.reward.unwrap(),
+        vec![vec![U256::zero(); 3]; 2]
+    );
+
+    for percentiles in [
+        vec![75.0, 25.0],
+        vec![25.0, 25.0],
+        vec![-1.0],
+        vec![100.5],
+        vec![10.0, 20.0, 30.0, 40.0],
+    ] {
+        let error = fee_history(percentiles.clone()).await.unwrap_err();
+        match error {
+            RpcError::JsonRpcError(error) => {
+                assert_eq!(error.code, ErrorCode::InvalidParams, "{:?}", percentiles);
+                assert!(
+                    error.message.contains("`rewardPercentiles`"),
+                    "{}",
+                    error.message
+                );
+            }
+            error => panic!("Unexpected error: {}", error),
+        }
+    }
+    Ok(())
+}
+
+/// Tests `eth_getLogs` with the `blockHash` filter
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn get_logs_by_block_hash() -> anyhow::Result<()> {
+    let call = |method: &'static str, params: Value| async move {
+        let (client, server) = local_client().await?;
+        let params = serde_json::from_value(params)?;
+        let fut = join(client.call_method(method, params), server);
+        Ok::<_, anyhow::Error>(fut.await.0)
+    };
+
+    let block = call("eth_getBlockByNumber", json!(["0x1", false]))
+        .await?
+        .unwrap();
+    let block_hash = block["hash"].clone();
+
+    let logs_by_hash = call("eth_getLogs", json!([{ "blockHash": block_hash }]))
+        .await?
+        .unwrap();
+    let logs_by_number = call(
+        "eth_getLogs",
+        json!([{ "fromBlock": "0x1", "toBlock": "0x1" }]),
+    )
+    .await?
+    .unwrap();
+    assert!(!logs_by_hash.as_array().unwrap().is_empty());
+    assert_eq!(logs_by_hash, logs_by_number);
+
+    match call(
+        "eth_getLogs",
+        json!([{ "blockHash": block_hash, "fromBlock": "0x1" }]),
+    )
+    .await?
+    .unwrap_err()
+    {
+        RpcError::JsonRpcError(error) => {
+            assert_eq!(error.code, ErrorCode::InvalidParams);
+            assert!(
+                error.message.contains("can't be combined"),
+                "{}",
+                error.message
+            );
+        }
+        error => panic!("Unexpected error: {}", error),
+    }
+
+    // The block is reverted within a DB transaction that is never committed,
+    // so the database stays intact for the other tests.
+    let cfg = TestServerConfig::default();
+    let rpc_app = Web3RpcApp::new(
+        cfg.pool.clone(),
+        &cfg.config.api.web3,
+        &cfg.config.api.token_config,
+    );
+    assert!(rpc_app.logs_index.read().unwrap().is_enabled());
+    let mut storage = cfg.pool.access_storage().await?;
+    let mut transaction = storage.start_transaction().await?;
+    let last_block = transaction
+        .chain()
+        .block_schema()
+        .get_last_saved_block()
+        .await?;
+    let last_block_hash = transaction
+        .chain()
+        .block_schema()
+        .get_storage_block(last_block)
+        .await?
+        .map(|block| H256::from_slice(&block.root_hash))
+        .unwrap();
+    let by_hash = |block_hash: H256| -> Filter {
+        serde_json::from_value(json!({ "blockHash": block_hash })).unwrap()
+    };
+    let up_to_block = |block_number: BlockNumber| -> Filter {
+        let to_block = U64::from(block_number.0);
+        serde_json::from_value(json!({ "fromBlock": "0x1", "toBlock": to_block })).unwrap()
+    };
+
+    rpc_app.update_logs_index(&mut transaction).await.unwrap();
+    let last_indexed_block = rpc_app.logs_index.read().unwrap().last_block();
+    assert_eq!(last_indexed_block, Some((last_block, last_block_hash)));
+
+    transaction
+        .chain()
+        .block_schema()
+        .remove_blocks(last_block - 1)
+        .await?;
+
+    let error = rpc_app
+        .logs_by_filter(&mut transaction, by_hash(last_block_hash))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidParams);
+    assert!(error.message.contains("`blockHash`"), "{}", error.message);
+
+    // Reverted blocks are dropped from the logs index as well.
+    rpc_app.update_logs_index(&mut transaction).await.unwrap();
+    rpc_app
+        .logs_by_filter(&mut transaction, up_to_block(last_block - 1))
+        .await
+        .unwrap();
+    let last_indexed_block = rpc_app.logs_index.read().unwrap().last_block();
+    assert_eq!(
+        last_indexed_block.map(|(block_number, _)| block_number),
+        Some(last_block - 1)
+    );
+    drop(transaction);
+
+    Ok(())
+}
+
+/// Tests that the logs index follows the saved blocks: a new block is appended to it,
+/// a reverted block is dropped from it before the logs are read, and `eth_getLogs` results
+/// match the storage in both cases.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn logs_index_follows_saved_blocks() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+
+    let indexed_app = Web3RpcApp::new(
+        cfg.pool.clone(),
+        &cfg.config.api.web3,
+        &cfg.config.api.token_config,
+    );
+    assert!(indexed_app.logs_index.read().unwrap().is_enabled());
+    let storage_app = Web3RpcApp::new(
+        cfg.pool.clone(),
+        &Web3Config {
+            logs_index_max_blocks: 0,
+            ..cfg.config.api.web3.clone()
+        },
+        &cfg.config.api.token_config,
+    );
+    let last_indexed_block = || {
+        indexed_app
+            .logs_index
+            .read()
+            .unwrap()
+            .last_block()
+            .map(|(block_number, _)| block_number)
+    };
+    let block_range = |from_block: BlockNumber, to_block: BlockNumber| -> Filter {
+        let from_block = U64::from(from_block.0);
+        let to_block = U64::from(to_block.0);
+        serde_json::from_value(json!({ "fromBlock": from_block, "toBlock": to_block })).unwrap()
+    };
+
+    // Blocks are saved and reverted within a DB transaction that is never committed,
+    // so the database stays intact for the other tests.
+    let mut storage = cfg.pool.access_storage().await?;
+    let mut transaction = storage.start_transaction().await?;
+    let last_block = transaction
+        .chain()
+        .block_schema()
+        .get_last_saved_block()
+        .await?;
+    indexed_app
+        .update_logs_index(&mut transaction)
+        .await
+        .unwrap();
+    assert_eq!(last_indexed_block(), Some(last_block));
+
+    let new_block = last_block + 1;
+    let txs = TestServerConfig::gen_zk_txs(1_000)
+        .txs
+        .into_iter()
+        .map(|(_, op)| op)
+        .collect();
+    transaction
+        .chain()
+        .block_schema()
+        .save_full_block(gen_sample_block(new_block, BLOCK_SIZE_CHUNKS, txs))
+        .await?;
+    indexed_app
+        .update_logs_index(&mut transaction)
+        .await
+        .unwrap();
+    assert_eq!(last_indexed_block(), Some(new_block));
+
+    let (indexed_logs, _) = indexed_app
+        .logs_by_filter(&mut transaction, block_range(new_block, new_block))
+        .await
+        .unwrap();
+    let (stored_logs, _) = storage_app
+        .logs_by_filter(&mut transaction, block_range(new_block, new_block))
+        .await
+        .unwrap();
+    assert!(!indexed_logs.is_empty());
+    assert!(indexed_logs
+        .iter()
+        .all(|log| log.block_number == Some(U64::from(new_block.0))));
+    assert_eq!(indexed_logs, stored_logs);
+
+    transaction
+        .chain()
+        .block_schema()
+        .remove_blocks(last_block)
+        .await?;
+    // The reverted block is dropped by `eth_getLogs` itself, without waiting for the index update.
+    assert_eq!(last_indexed_block(), Some(new_block));
+
+    let error = indexed_app
+        .logs_by_filter(&mut transaction, block_range(new_block, new_block))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidParams);
+    let (indexed_logs, _) = indexed_app
+        .logs_by_filter(&mut transaction, block_range(BlockNumber(1), last_block))
+        .await
+        .unwrap();
+    let (stored_logs, _) = storage_app
+        .logs_by_filter(&mut transaction, block_range(BlockNumber(1), last_block))
+        .await
+        .unwrap();
+    assert_eq!(last_indexed_block(), Some(last_block));
+    assert!(indexed_logs
+        .iter()
+        .all(|log| log.block_number <= Some(U64::from(last_block.0))));
+    assert_eq!(indexed_logs, stored_logs);
+    drop(transaction);
+
+    Ok(())
+}
+
+/// Tests that balances for the `latest` tag match the committed or the finalized state
+/// depending on the `latest_includes_unfinalized_blocks` config option.
+#[tokio::test(flavor = "multi_thread")]
+#[cfg_attr(
+    not(feature = "api_test"),
+    ignore = "Use `zk test rust-api` command to perform this test"
+)]
+async fn latest_tag_config() -> anyhow::Result<()> {
+    let cfg = TestServerConfig::default();
+    cfg.fill_database().await?;
+
+    // Finds an account whose balance was changed by a block that is committed but not finalized.
+    let (address, committed_block, committed_balance, finalized_block, finalized_balance) = {
+        let mut storage = cfg.pool.access_storage().await?;
+        let committed_block = storage
+            .chain()
+            .block_schema()
+            .get_last_committed_confirmed_block()
+            .await?;
+        let (_, committed_accounts) = storage
+            .chain()
+            .state_schema()
+            .load_committed_state(Some(committed_block))
+            .await?;
+        let (finalized_block, finalized_accounts) =
+            storage.chain().state_schema().load_verified_state().await?;
+        let address = committed_accounts
+            .iter()
+            .find(|(id, account)| {
+                let finalized_balance = finalized_accounts
+                    .get(*id)
+                    .map(|account| account.get_balance(TokenId(0)))
+                    .unwrap_or_default();
+                account.get_balance(TokenId(0)) != finalized_balance
+            })
+            .map(|(_, account)| account.address)
+            .expect("there is no account with unfinalized balance changes");
+
+        let committed_balance = storage
+            .chain()
+            .account_schema()
+            .get_account_balance_for_block(address, committed_block, TokenId(0))
+            .await?;
+        let finalized_balance = storage
+            .chain()
+            .account_schema()
+            .get_account_balance_for_block(address, finalized_block, TokenId(0))
+            .await?;
+        let (committed_balance, finalized_balance) = (
+            u256_from_biguint(committed_balance),
+            u256_from_biguint(finalized_balance),
+        );
+        assert_ne!(committed_balance, finalized_balance);
+        (
+            address,
+            committed_block,
+            committed_balance,
+            finalized_block,
+            finalized_balance,
+        )
+    };
+
+    let call = |config: Web3Config, method: &'static str, params: Vec<Value>| {
+        let (client, server) = local_client_with_config(cfg.pool.clone(), &config);
+        async move {
+            join(client.call_method(method, Params::Array(params)), server)
+                .await
+                .0
+                .unwrap()
+        }
+    };
+
+    for (includes_unfinalized, latest_block, latest_balance) in [
+        (true, committed_block, committed_balance),
+        (false, finalized_block, finalized_balance),
+    ] {
+        let config = Web3Config {
+            latest_includes_unfinalized_blocks: includes_unfinalized,
+            ..cfg.config.api.web3.clone()
+        };
+
+        let block_number = call(config.clone(), "eth_blockNumber", vec![]).await;
+        assert_eq!(block_number, json!(U64::from(latest_block.0)));
+
+        for (tag, expected) in [
+            (Some("latest"), latest_balance),
+            (None, latest_balance),
+            (Some("committed"), committed_balance),
+            (Some("finalized"), finalized_balance),
+        ] {
+            let mut params = vec![json!(address)];
+            params.extend(tag.map(|tag| json!(tag)));
+            let balance = call(config.clone(), "eth_getBalance", params).await;
+            assert_eq!(
+                serde_json::from_value::<U256>(balance)?,
+                expected,
+                "{:?}, includes unfinalized: {}",
+                tag,
+                includes_unfinalized
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Generates a JSON value for one of the filter fields, valid or malformed.
+fn random_filter_field(rng: &mut XorShiftRng, field: &str) -> Value {
+    let random_hex = |rng: &mut XorShiftRng| {
+        let len = rng.gen_range(0, 70);
+        let digits: String = (0..len)
+            .map(|_| *rng.choose(b"0123456789abcdefABCDEFxz").unwrap() as char)
+            .collect();
+        Value::String(format!("0x{}", digits))
+    };
+    let random_topic = |rng: &mut XorShiftRng| match rng.gen_range(0, 4) {
+        0 => Value::Null,
+        1 => json!(H256::from_low_u64_be(rng.gen())),
+        2 => json!([H256::from_low_u64_be(rng.gen()), random_hex(rng)]),
+        _ => random_hex(rng),
+    };
+    let any_value = |rng: &mut XorShiftRng| match rng.gen_range(0, 6) {
+        0 => json!(rng.gen::<u64>()),
+        1 => json!(rng.gen::<bool>()),
+        2 => json!({ "nested": [] }),
+        3 => json!([[[]]]),
+        4 => json!(-1.5),
+        _ => json!(""),
+    };
+
+    match (field, rng.gen_range(0, 8)) {
+        (_, 0) => Value::Null,
+        (_, 1) => any_value(rng),
+        (_, 2) => random_hex(rng),
+        ("fromBlock", _) | ("toBlock", _) => match rng.gen_range(0, 5) {
+            0 => json!(*rng
+                .choose(&[
+                    "committed",
+                    "finalized",
+                    "safe",
+                    "latest",
+                    "earliest",
+                    "pending"
+                ])
+                .unwrap()),
+            1 => json!(U64::from(rng.gen::<u32>())),
+            2 => json!("0x1ffffffffffffffff"),
+            3 => json!("0x"),
+            _ => json!("Latest"),
+        },
+        ("blockHash", _) => match rng.gen_range(0, 2) {
+            0 => json!(H256::from_low_u64_be(rng.gen())),
+            _ => json!(H160::from_low_u64_be(rng.gen())),
+        },
+        ("address", _) => match rng.gen_range(0, 3) {
+            0 => json!(H160::from_low_u64_be(rng.gen())),
+            1 => Value::Array(Vec::new()),
+            _ => Value::Array(
+                (0..rng.gen_range(1, 4))
+                    .map(|_| json!(H160::from_low_u64_be(rng.gen())))
+                    .collect(),
+            ),
+        },
+        ("topics", _) => Value::Array(
+            (0..rng.gen_range(0, 6))
+                .map(|_| random_topic(rng))
+                .collect(),
+        ),
+        _ => any_value(rng),
+    }
+}
+
+#[test]
+/// Tests that deserialization of randomized and malformed filters never panics
+/// and either succeeds or returns an error.
+fn filter_deserialization_fuzzing() {
+    let deserialize = |text: &str| {
+        std::panic::catch_unwind(|| serde_json::from_str::<Filter>(text))
+            .unwrap_or_else(|_| panic!("Filter deserialization panicked on {}", text))
+    };
+
+    // Known tricky cases: empty arrays, over-length topics and `blockHash` combined with a range.
+    // The latter is well-formed and rejected by `eth_getLogs` itself.
+    let filter = deserialize(r#"{"address": [], "topics": []}"#).unwrap();
+    assert_eq!(filter.address, Some(ValueOrArray(Vec::new())));
+    assert_eq!(filter.topics, Some(Vec::new()));
+    let topic = H256::repeat_byte(1);
+    let filter =
+        deserialize(&json!({ "topics": [topic, null, [], [topic], null, topic] }).to_string())
+            .unwrap();
+    assert_eq!(filter.topics.unwrap().len(), 6);
+    let filter = deserialize(
+        &json!({ "blockHash": H256::repeat_byte(2), "fromBlock": "0x1", "toBlock": "latest" })
+            .to_string(),
+    )
+    .unwrap();
+    assert!(filter.block_hash.is_some() && filter.from_block.is_some());
+    for text in [
+        "",
+        "null",
+        "42",
+        "{",
+        r#"{"topics": [[[]]]}"#,
+        r#"{"address": [null]}"#,
+        r#"{"fromBlock": {}}"#,
+        r#"{"blockHash": "0x"}"#,
+    ] {
+        let err = deserialize(text).unwrap_err();
+        assert!(!err.to_string().is_empty(), "{}", text);
+    }
+
+    let fields = [
+        "fromBlock",
+        "toBlock",
+        "blockHash",
+        "address",
+        "topics",
+        "unknown",
+    ];
+    let mut rng = XorShiftRng::from_seed([1, 2, 3, 4]);
+    for _ in 0..10_000 {
+        let mut filter = Map::new();
+        for field in fields.iter() {
+            if rng.gen() {
+                filter.insert(field.to_string(), random_filter_field(&mut rng, field));
+            }
+        }
+        let mut text = Value::Object(filter).to_string();
+        // Corrupt some of the inputs to check the malformed JSON as well.
+        if rng.gen_weighted_bool(4) {
+            let position = rng.gen_range(0, text.len());
+            if text.is_char_boundary(position) {
+                text.truncate(position);
+            }
+        }
+        if let Err(err) = deserialize(&text) {
+            assert!(!err.to_string().is_empty(), "{}", text);
+        }
+    }
+}
@@ -0,0 +1,278 @@
+// Built-in uses
+// External uses
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+// Workspace uses
+// Local uses
+use super::{serialize_quantity, Block, Transaction, H160, H256, H64, U256, U64};
+
+/// Block Number
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BlockNumber {
+    /// Last block that was committed on L1.
+    Committed,
+    /// Last block that was finalized on L1.
+    Finalized,
+    /// Alias for `BlockNumber::Finalized`: finalized blocks can't be reverted.
+    Safe,
+    /// Latest block (may be the block that is currently open).
+    Latest,
+    /// Earliest block (genesis)
+    Earliest,
+    /// Last block saved by the server, or an alias for `BlockNumber::Latest`,
+    /// depending on the `pending_includes_unconfirmed_blocks` config option.
+    Pending,
+    /// Block by number from canon chain
+    Number(U64),
+}
+
+impl Serialize for BlockNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            BlockNumber::Number(ref x) => serialize_quantity(x, serializer),
+            BlockNumber::Committed => serializer.serialize_str("committed"),
+            BlockNumber::Finalized => serializer.serialize_str("finalized"),
+            BlockNumber::Safe => serializer.serialize_str("safe"),
+            BlockNumber::Latest => serializer.serialize_str("latest"),
+            BlockNumber::Earliest => serializer.serialize_str("earliest"),
+            BlockNumber::Pending => serializer.serialize_str("pending"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BlockNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct V;
+        impl<'de> serde::de::Visitor<'de> for V {
+            type Value = BlockNumber;
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("A block number or one of the supported aliases")
+            }
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                let result = match value {
+                    "committed" => BlockNumber::Committed,
+                    "finalized" => BlockNumber::Finalized,
+                    "safe" => BlockNumber::Safe,
+                    "latest" => BlockNumber::Latest,
+                    "earliest" => BlockNumber::Earliest,
+                    "pending" => BlockNumber::Pending,
+                    num => {
+                        let number =
+                            U64::deserialize(de::value::BorrowedStrDeserializer::<E>::new(num))
+                                .map_err(|err| {
+                                    E::custom(format!("invalid block number `{}`: {}", num, err))
+                                })?;
+                        BlockNumber::Number(number)
+                    }
+                };
+
+                Ok(result)
+            }
+        }
+        deserializer.deserialize_str(V)
+    }
+}
+
+/// Defines a newtype for a positional RPC parameter, so that its deserialization errors
+/// name the parameter like the errors of the named fields do.
+macro_rules! named_param {
+    ($(#[$attr:meta])* $param:ident($inner:ty) = $name:literal) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+        #[serde(transparent)]
+        pub struct $param(pub $inner);
+
+        impl<'de> Deserialize<'de> for $param {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                <$inner>::deserialize(deserializer)
+                    .map(Self)
+                    .map_err(|err| de::Error::custom(format!("invalid `{}`: {}", $name, err)))
+            }
+        }
+    };
+}
+
+named_param!(
+    /// The `block` parameter of the methods reading the state at some block.
+    BlockParam(BlockNumber) = "block"
+);
+named_param!(
+    /// The `blockCount` parameter of `eth_feeHistory` method.
+    BlockCountParam(U64) = "blockCount"
+);
+named_param!(
+    /// The `newestBlock` parameter of `eth_feeHistory` method.
+    NewestBlockParam(BlockNumber) = "newestBlock"
+);
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum BlockInfo {
+    BlockWithHashes(Block<H256>),
+    BlockWithTxs(Block<Transaction>),
+}
+
+impl BlockInfo {
+    /// Returns `gasUsed` and `gasLimit` of a block. zkSync doesn't meter gas,
+    /// so they don't depend on the block contents.
+    pub fn gas() -> (U256, U256) {
+        (0.into(), 50000.into())
+    }
+
+    /// Returns the ratio of `gasUsed` to `gasLimit` of a block.
+    pub fn gas_used_ratio() -> f64 {
+        let (gas_used, gas_limit) = Self::gas();
+        gas_used.as_u64() as f64 / gas_limit.as_u64() as f64
+    }
+
+    fn new_block<T>(
+        hash: H256,
+        parent_hash: H256,
+        block_number: zksync_types::BlockNumber,
+        timestamp: u64,
+        transactions: Vec<T>,
+    ) -> Block<T> {
+        let (gas_used, gas_limit) = Self::gas();
+        Block {
+            hash: Some(hash),
+            parent_hash,
+            uncles_hash: H256::zero(),
+            author: H160::zero(),
+            state_root: hash,
+            transactions_root: hash,
+            receipts_root: hash,
+            number: Some(block_number.0.into()),
+            gas_used,
+            gas_limit,
+            base_fee_per_gas: None,
+            extra_data: Vec::new().into(),
+            logs_bloom: None,
+            timestamp: timestamp.into(),
+            difficulty: 0.into(),
+            total_difficulty: Some(0.into()),
+            seal_fields: Vec::new(),
+            uncles: Vec::new(),
+            transactions,
+            size: None,
+            mix_hash: Some(H256::zero()),
+            nonce: Some(H64::zero()),
+        }
+    }
+
+    pub fn new_with_hashes(
+        hash: H256,
+        parent_hash: H256,
+        block_number: zksync_types::BlockNumber,
+        timestamp: u64,
+        transactions: Vec<H256>,
+    ) -> Self {
+        Self::BlockWithHashes(Self::new_block(
+            hash,
+            parent_hash,
+            block_number,
+            timestamp,
+            transactions,
+        ))
+    }
+
+    pub fn new_with_txs(
+        hash: H256,
+        parent_hash: H256,
+        block_number: zksync_types::BlockNumber,
+        timestamp: u64,
+        transactions: Vec<Transaction>,
+    ) -> Self {
+        Self::BlockWithTxs(Self::new_block(
+            hash,
+            parent_hash,
+            block_number,
+            timestamp,
+            transactions,
+        ))
+    }
+
+    /// Creates a block with every header field populated, for tests.
+    ///
+    /// Hashes and timestamps are derived from the block number, so the blocks created for
+    /// consecutive numbers form a consistent chain.
+    #[cfg(test)]
+    pub fn test_block(block_number: zksync_types::BlockNumber, transactions: Vec<H256>) -> Self {
+        let hash = |number: u32| H256::from_low_u64_be(u64::from(number) + 1);
+        let parent_hash = match block_number.0 {
+            0 => H256::zero(),
+            number => hash(number - 1),
+        };
+        let mut block = Self::new_block(
+            hash(block_number.0),
+            parent_hash,
+            block_number,
+            u64::from(block_number.0) * 10,
+            transactions,
+        );
+        block.author = H160::repeat_byte(0x11);
+        block.base_fee_per_gas = Some(U256::zero());
+        block.size = Some(U256::zero());
+        Self::BlockWithHashes(block)
+    }
+
+    /// Removes the total difficulty, so that the `totalDifficulty` field is omitted from the response.
+    pub fn without_total_difficulty(self) -> Self {
+        match self {
+            Self::BlockWithHashes(mut block) => {
+                block.total_difficulty = None;
+                Self::BlockWithHashes(block)
+            }
+            Self::BlockWithTxs(mut block) => {
+                block.total_difficulty = None;
+                Self::BlockWithTxs(block)
+            }
+        }
+    }
+}
+
+impl Serialize for BlockInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut block = match self {
+            BlockInfo::BlockWithHashes(block) => serde_json::to_value(block),
+            BlockInfo::BlockWithTxs(block) => serde_json::to_value(block),
+        }
+        .map_err(ser::Error::custom)?;
+        // Missing total difficulty is omitted rather than serialized as `null`.
+        if let Some(fields) = block.as_object_mut() {
+            if matches!(fields.get("totalDifficulty"), Some(serde_json::Value::Null)) {
+                fields.remove("totalDifficulty");
+            }
+        }
+        block.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeHistory {
+    /// Lowest number block of the returned range.
+    #[serde(serialize_with = "serialize_quantity")]
+    pub oldest_block: U64,
+    /// Base fees per gas for each block in the range and the next block after it.
+    #[serde(serialize_with = "serialize_quantity")]
+    pub base_fee_per_gas: Vec<U256>,
+    /// Ratios of gas used to gas limit for each block in the range.
+    pub gas_used_ratio: Vec<f64>,
+    /// Effective priority fees at the requested percentiles for each block in the range.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_quantity"
+    )]
+    pub reward: Option<Vec<Vec<U256>>>,
+}
@@ -0,0 +1,21 @@
+// Built-in uses
+// External uses
+use serde::{Deserialize, Serialize};
+// Workspace uses
+// Local uses
+use super::{serialize_data, serialize_quantity, Bytes, H160, U256};
+
+#[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallRequest {
+    pub from: Option<H160>,
+    pub to: H160,
+    #[serde(serialize_with = "serialize_quantity")]
+    pub gas: Option<U256>,
+    #[serde(serialize_with = "serialize_quantity")]
+    pub gas_price: Option<U256>,
+    #[serde(serialize_with = "serialize_quantity")]
+    pub value: Option<U256>,
+    #[serde(serialize_with = "serialize_data")]
+    pub data: Option<Bytes>,
+}
@@ -0,0 +1,106 @@
+// Built-in uses
+use std::fmt;
+use std::marker::PhantomData;
+// External uses
+use itertools::unfold;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+// Workspace uses
+// Local uses
+use super::{BlockNumber, H160, H256};
+
+/// Either value or array of values.
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct ValueOrArray<T>(pub Vec<T>);
+
+impl<'de, T: fmt::Debug + Deserialize<'de>> ::serde::Deserialize<'de> for ValueOrArray<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        struct Visitor<T>(PhantomData<T>);
+
+        impl<'de, T: fmt::Debug + Deserialize<'de>> de::Visitor<'de> for Visitor<T> {
+            type Value = ValueOrArray<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("Expected value or sequence")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                use serde::de::IntoDeserializer;
+
+                Deserialize::deserialize(value.into_deserializer())
+                    .map(|value| ValueOrArray(vec![value]))
+            }
+
+            fn visit_seq<S>(self, visitor: S) -> Result<Self::Value, S::Error>
+            where
+                S: de::SeqAccess<'de>,
+            {
+                unfold(visitor, |vis| vis.next_element().transpose())
+                    .collect::<Result<_, _>>()
+                    .map(ValueOrArray)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor(PhantomData))
+    }
+}
+
+impl<T> Serialize for ValueOrArray<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // An empty array isn't serialized as `null`, since they are not equivalent for `address`.
+        match self.0.len() {
+            1 => Serialize::serialize(&self.0[0], serializer),
+            _ => Serialize::serialize(&self.0, serializer),
+        }
+    }
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Filter {
+    #[serde(default, deserialize_with = "deserialize_from_block")]
+    pub from_block: Option<BlockNumber>,
+    #[serde(default, deserialize_with = "deserialize_to_block")]
+    pub to_block: Option<BlockNumber>,
+    /// Hash of the only block to get the logs of, can't be combined with `fromBlock` and `toBlock`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<H256>,
+    /// Omitted or `null` address matches logs of any address, an empty array matches none.
+    pub address: Option<ValueOrArray<H160>>,
+    pub topics: Option<Vec<Option<ValueOrArray<H256>>>>,
+}
+
+/// Deserializes the optional block number, naming the field in the error message.
+fn deserialize_block_number<'de, D>(
+    deserializer: D,
+    name: &str,
+) -> Result<Option<BlockNumber>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<BlockNumber>::deserialize(deserializer)
+        .map_err(|err| de::Error::custom(format!("invalid `{}`: {}", name, err)))
+}
+
+fn deserialize_from_block<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<BlockNumber>, D::Error> {
+    deserialize_block_number(deserializer, "fromBlock")
+}
+
+fn deserialize_to_block<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<BlockNumber>, D::Error> {
+    deserialize_block_number(deserializer, "toBlock")
+}
@@ -0,0 +1,39 @@
+// Built-in uses
+// External uses
+use serde::{Deserialize, Serialize};
+// Workspace uses
+// Local uses
+use super::{serialize_quantity, Log, H256, U64};
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum Event {
+    ZkSyncTransfer,
+    ZkSyncWithdraw,
+    ZkSyncForcedExit,
+    ZkSyncChangePubKey,
+    ZkSyncDeposit,
+    ZkSyncFullExit,
+    ZkSyncMintNFT,
+    ZkSyncWithdrawNFT,
+    ZkSyncSwap,
+    ERCTransfer, // ERC20 and ERC721 transfers have same topics
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CommonLogData {
+    pub block_hash: Option<H256>,
+    pub block_number: Option<U64>,
+    pub transaction_hash: H256,
+    pub transaction_index: Option<U64>,
+}
+
+/// Response of the `zks_getLogsWithMeta` method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogsWithMeta {
+    pub logs: Vec<Log>,
+    /// The highest finalized block covered by the response.
+    /// Logs up to this block can't be affected by reverts.
+    #[serde(serialize_with = "serialize_quantity")]
+    pub finalized_block: U64,
+}
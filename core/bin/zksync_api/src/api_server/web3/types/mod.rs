@@ -0,0 +1,117 @@
+//! Web3 API types definitions.
+//!
+//! Most of the types are re-exported from the `web3` crate, but some of them maybe extended with
+//! new variants (enums) or optional fields (structures).
+//!
+//! These "extensions" are required to provide more zkSync-specific information while remaining Web3-compilant.
+//!
+//! The re-exported types already serialize data as even-length hex strings preserving leading
+//! zero bytes, and quantities as hex strings without leading zeros. The quantity (`U64`, `U256`)
+//! and byte array (`Bytes`) fields of the types defined here follow the same rules by means of
+//! `serialize_quantity` and `serialize_data`.
+
+// Built-in uses
+// External uses
+use serde::{Serialize, Serializer};
+pub use web3::types::{
+    Block, Bytes, Log, Transaction, TransactionReceipt, H160, H2048, H256, H64, U256, U64,
+};
+// Workspace uses
+// Local uses
+pub use self::{
+    block::{BlockCountParam, BlockInfo, BlockNumber, BlockParam, FeeHistory, NewestBlockParam},
+    call::CallRequest,
+    filter::{Filter, ValueOrArray},
+    log::{CommonLogData, Event, LogsWithMeta},
+    token::TokenInfo,
+    transaction::{TransactionDetails, TxData},
+};
+
+mod block;
+mod call;
+mod filter;
+mod log;
+mod token;
+mod transaction;
+
+/// Value serialized by `serialize_quantity`: a quantity, or an optional value or a list of them.
+pub trait HexQuantity {
+    fn serialize_quantity<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+macro_rules! impl_hex_quantity {
+    ($($ty:ty),*) => {
+        $(
+            impl HexQuantity for $ty {
+                fn serialize_quantity<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(&format!("0x{:x}", self))
+                }
+            }
+        )*
+    };
+}
+
+impl_hex_quantity!(U64, U256);
+
+impl<T: HexQuantity> HexQuantity for Option<T> {
+    fn serialize_quantity<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => value.serialize_quantity(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<T: HexQuantity> HexQuantity for Vec<T> {
+    fn serialize_quantity<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Item<'a, T>(&'a T);
+
+        impl<T: HexQuantity> Serialize for Item<'_, T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize_quantity(serializer)
+            }
+        }
+
+        serializer.collect_seq(self.iter().map(Item))
+    }
+}
+
+/// Value serialized by `serialize_data`: a byte array or an optional one.
+pub trait HexData {
+    fn serialize_data<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+impl HexData for Bytes {
+    fn serialize_data<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", hex::encode(&self.0)))
+    }
+}
+
+impl<T: HexData> HexData for Option<T> {
+    fn serialize_data<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Some(value) => value.serialize_data(serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Serializes a quantity as a `0x`-prefixed hex string without leading zeros, as required
+/// by the Ethereum JSON-RPC spec: `256` is serialized as `0x100` and `0` as `0x0`.
+pub fn serialize_quantity<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: HexQuantity,
+    S: Serializer,
+{
+    value.serialize_quantity(serializer)
+}
+
+/// Serializes a byte array as a `0x`-prefixed hex string with two digits per byte, so that
+/// leading zero bytes are preserved and the string is always of even length.
+pub fn serialize_data<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: HexData,
+    S: Serializer,
+{
+    value.serialize_data(serializer)
+}
@@ -0,0 +1,29 @@
+// Built-in uses
+// External uses
+use serde::{Deserialize, Serialize};
+// Workspace uses
+// Local uses
+use super::{serialize_quantity, H160, U64};
+
+/// Registered token returned by the `zks_getTokens` method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenInfo {
+    #[serde(serialize_with = "serialize_quantity")]
+    pub id: U64,
+    /// Address of the token contract on L1, zero for ETH.
+    pub address: H160,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl From<zksync_types::Token> for TokenInfo {
+    fn from(token: zksync_types::Token) -> Self {
+        Self {
+            id: U64::from(*token.id),
+            address: token.address,
+            symbol: token.symbol,
+            decimals: token.decimals,
+        }
+    }
+}
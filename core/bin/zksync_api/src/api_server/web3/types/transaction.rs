@@ -0,0 +1,34 @@
+// Built-in uses
+// External uses
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+// Workspace uses
+// Local uses
+use super::{Transaction, H160, H256, U256};
+
+#[derive(Debug, Clone)]
+pub struct TxData {
+    pub block_hash: H256,
+    pub block_number: u32,
+    pub block_index: Option<u32>,
+    pub from: H160,
+    pub to: Option<H160>,
+    pub nonce: u32,
+    pub tx_hash: H256,
+    pub value: U256,
+}
+
+/// Response of the `zks_getTransactionDetails` method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDetails {
+    pub hash: H256,
+    /// `true` if the transaction is in the mempool and is not included into a block yet.
+    pub pending: bool,
+    /// Time when the pending transaction was received by the server.
+    pub received_at: Option<DateTime<Utc>>,
+    /// `true` if the transaction has been pending for longer than the configured threshold.
+    pub may_be_stuck: bool,
+    /// The transaction as returned by `eth_getTransactionByHash`, if it's included into a block.
+    pub transaction: Option<Transaction>,
+}
@@ -0,0 +1,64 @@
+// Built-in uses
+// External uses
+use futures::future::{self, Either, Future, Ready};
+use jsonrpc_core::{
+    Call, Error, ErrorCode, Failure, Id, Metadata, MethodCall, Middleware, Output, Version,
+};
+// Workspace uses
+// Local uses
+
+/// Rejects the calls that are not JSON-RPC 2.0 ones.
+///
+/// The JSON-RPC library accepts calls without the `jsonrpc` field as 1.0 ones and responds to them
+/// and to invalid calls without the `jsonrpc` field. This middleware answers such calls with
+/// the "invalid request" error, so that every response carries `jsonrpc: "2.0"`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonRpcVersionMiddleware;
+
+impl JsonRpcVersionMiddleware {
+    fn failure(id: Id, error: Error) -> Option<Output> {
+        Some(Output::Failure(Failure {
+            jsonrpc: Some(Version::V2),
+            error,
+            id,
+        }))
+    }
+
+    fn unsupported_version(id: Id) -> Option<Output> {
+        Self::failure(
+            id,
+            Error {
+                code: ErrorCode::InvalidRequest,
+                message: "Only JSON-RPC 2.0 requests are supported".to_string(),
+                data: None,
+            },
+        )
+    }
+}
+
+impl<M: Metadata> Middleware<M> for JsonRpcVersionMiddleware {
+    type Future = jsonrpc_core::FutureResponse;
+    type CallFuture = Ready<Option<Output>>;
+
+    fn on_call<F, X>(&self, call: Call, meta: M, next: F) -> Either<Self::CallFuture, X>
+    where
+        F: Fn(Call, M) -> X + Send + Sync,
+        X: Future<Output = Option<Output>> + Send + 'static,
+    {
+        match call {
+            Call::MethodCall(MethodCall {
+                jsonrpc: None, id, ..
+            }) => Either::Left(future::ready(Self::unsupported_version(id))),
+            // Notifications are never answered.
+            Call::Notification(notification) if notification.jsonrpc.is_none() => {
+                Either::Left(future::ready(None))
+            }
+            // Calls may be invalid for other reasons than the version, e.g. a missing `method`,
+            // so they are answered with the standard error.
+            Call::Invalid { id } => {
+                Either::Left(future::ready(Self::failure(id, Error::invalid_request())))
+            }
+            call => Either::Right(next(call, meta)),
+        }
+    }
+}
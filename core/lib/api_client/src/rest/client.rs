@@ -5,6 +5,9 @@ use reqwest::StatusCode;
 use serde::{de::DeserializeOwned, ser::Serialize};
 use thiserror::Error;
 
+// Workspace uses
+use zksync_api_types::v02::Response;
+
 // Local uses
 use super::error::ErrorBody;
 
@@ -129,4 +132,30 @@ impl ClientRequestBuilder {
             })
         }
     }
+
+    /// Version of `send` for the REST API v0.2 endpoints.
+    ///
+    /// API v0.2 reports errors in the `Response` envelope along with the HTTP status, so
+    /// the envelope is returned for non-2xx statuses as well. Any other non-2xx response is an error.
+    pub async fn send_v02(self) -> self::Result<Response> {
+        let response = self.inner.send().await?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response.json().await.map_err(ClientError::Parse)?);
+        }
+
+        let body = response.bytes().await.map_err(ClientError::Parse)?;
+        if let Ok(response) = serde_json::from_slice::<Response>(&body) {
+            return Ok(response);
+        }
+        if status == StatusCode::NOT_FOUND {
+            return Err(ClientError::NotFound(self.url));
+        }
+
+        Err(ClientError::BadRequest {
+            http_code: status,
+            body: serde_json::from_slice(&body).unwrap_or_default(),
+        })
+    }
 }
@@ -16,7 +16,7 @@ impl Client {
             super::API_V02_SCOPE,
             &format!("accounts/{}/{}", account_id_or_address, state_type),
         )
-        .send()
+        .send_v02()
         .await
     }
 
@@ -25,7 +25,7 @@ impl Client {
             super::API_V02_SCOPE,
             &format!("accounts/{}", account_id_or_address),
         )
-        .send()
+        .send_v02()
         .await
     }
 
@@ -39,7 +39,7 @@ impl Client {
             &format!("accounts/{}/transactions", account_id_or_address),
         )
         .query(&pagination_query)
-        .send()
+        .send_v02()
         .await
     }
 
@@ -53,7 +53,7 @@ impl Client {
             &format!("accounts/{}/transactions/pending", account_id_or_address),
         )
         .query(pagination_query)
-        .send()
+        .send_v02()
         .await
     }
 }
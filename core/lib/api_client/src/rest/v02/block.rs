@@ -9,7 +9,7 @@ use zksync_types::{tx::TxHash, BlockNumber};
 impl Client {
     pub async fn block_by_position(&self, block_position: &str) -> Result<Response> {
         self.get_with_scope(super::API_V02_SCOPE, &format!("blocks/{}", block_position))
-            .send()
+            .send_v02()
             .await
     }
 
@@ -18,7 +18,7 @@ impl Client {
             super::API_V02_SCOPE,
             &format!("blocks/{}/transactions/{}", block_number, tx_index),
         )
-        .send()
+        .send_v02()
         .await
     }
 
@@ -32,7 +32,7 @@ impl Client {
             &format!("blocks/{}/transactions", block_position),
         )
         .query(&pagination_query)
-        .send()
+        .send_v02()
         .await
     }
 
@@ -42,7 +42,7 @@ impl Client {
     ) -> Result<Response> {
         self.get_with_scope(super::API_V02_SCOPE, "blocks")
             .query(pagination_query)
-            .send()
+            .send_v02()
             .await
     }
 }
@@ -19,7 +19,7 @@ impl Client {
                 address,
                 token_like,
             })
-            .send()
+            .send_v02()
             .await
     }
 
@@ -33,7 +33,7 @@ impl Client {
                 transactions,
                 token_like,
             })
-            .send()
+            .send_v02()
             .await
     }
 }
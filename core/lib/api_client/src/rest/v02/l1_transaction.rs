@@ -0,0 +1,14 @@
+use crate::rest::client::{Client, Result};
+use zksync_api_types::v02::Response;
+use zksync_types::H256;
+
+impl Client {
+    pub async fn l1_transaction(&self, tx_hash: H256) -> Result<Response> {
+        self.get_with_scope(
+            super::API_V02_SCOPE,
+            &format!("l1Transactions/{:?}", tx_hash),
+        )
+        .send_v02()
+        .await
+    }
+}
@@ -2,6 +2,7 @@ pub mod account;
 pub mod block;
 pub mod config;
 pub mod fee;
+pub mod l1_transaction;
 pub mod status;
 pub mod token;
 pub mod transaction;
@@ -12,13 +12,13 @@ impl Client {
     ) -> Result<Response> {
         self.get_with_scope(super::API_V02_SCOPE, "tokens")
             .query(&pagination_query)
-            .send()
+            .send_v02()
             .await
     }
 
     pub async fn token_by_id(&self, token: &TokenLike) -> Result<Response> {
         self.get_with_scope(super::API_V02_SCOPE, &format!("tokens/{}", token))
-            .send()
+            .send_v02()
             .await
     }
 
@@ -27,19 +27,19 @@ impl Client {
             super::API_V02_SCOPE,
             &format!("tokens/{}/priceIn/{}", token, token_id_or_usd),
         )
-        .send()
+        .send_v02()
         .await
     }
 
     pub async fn nft_by_id(&self, id: TokenId) -> Result<Response> {
         self.get_with_scope(super::API_V02_SCOPE, &format!("tokens/nft/{}", id))
-            .send()
+            .send_v02()
             .await
     }
 
     pub async fn nft_owner_by_id(&self, id: TokenId) -> Result<Response> {
         self.get_with_scope(super::API_V02_SCOPE, &format!("tokens/nft/{}/owner", id))
-            .send()
+            .send_v02()
             .await
     }
 
@@ -48,7 +48,7 @@ impl Client {
             super::API_V02_SCOPE,
             &format!("tokens/nft_id_by_tx_hash/{}", tx_hash.to_string()),
         )
-        .send()
+        .send_v02()
         .await
     }
 }
@@ -13,7 +13,7 @@ impl Client {
     ) -> Result<Response> {
         self.post_with_scope(super::API_V02_SCOPE, "transactions")
             .body(&TxWithSignature { tx, signature })
-            .send()
+            .send_v02()
             .await
     }
 
@@ -24,7 +24,7 @@ impl Client {
     ) -> Result<Response> {
         self.post_with_scope(super::API_V02_SCOPE, "transactions/batches")
             .body(&IncomingTxBatch { txs, signature })
-            .send()
+            .send_v02()
             .await
     }
 
@@ -33,7 +33,7 @@ impl Client {
             super::API_V02_SCOPE,
             &format!("transactions/{}", tx_hash.to_string()),
         )
-        .send()
+        .send_v02()
         .await
     }
 
@@ -42,7 +42,7 @@ impl Client {
             super::API_V02_SCOPE,
             &format!("transactions/{}/data", tx_hash.to_string()),
         )
-        .send()
+        .send_v02()
         .await
     }
 
@@ -51,7 +51,7 @@ impl Client {
             super::API_V02_SCOPE,
             &format!("transactions/batches/{}", batch_hash.to_string()),
         )
-        .send()
+        .send_v02()
         .await
     }
 }
@@ -18,7 +18,9 @@ pub struct BlockInfo {
     pub new_state_root: Fr,
     pub block_size: u64,
     pub commit_tx_hash: Option<H256>,
+    pub prove_tx_hash: Option<H256>,
     pub verify_tx_hash: Option<H256>,
+    pub execute_tx_hash: Option<H256>,
     pub committed_at: DateTime<Utc>,
     pub finalized_at: Option<DateTime<Utc>>,
     pub status: BlockStatus,
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use zksync_types::{aggregated_operations::AggregatedActionType, tx::TxHash, BlockNumber, H256};
+
+use super::block::BlockInfo;
+
+/// Aggregated operation sent to L1 within an Ethereum transaction.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct L1TransactionInfo {
+    /// Hash of the Ethereum transaction that was mined for the operation.
+    /// It may differ from the requested one if the transaction was resent.
+    pub final_tx_hash: Option<H256>,
+    pub operation_type: AggregatedActionType,
+    pub from_block: BlockNumber,
+    pub to_block: BlockNumber,
+    pub confirmed: bool,
+    /// Blocks included in the operation.
+    pub blocks: Vec<BlockInfo>,
+    /// Withdrawals finalized by the operation.
+    /// Only present for the `ExecuteBlocks` operations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub withdrawals: Option<Vec<TxHash>>,
+}
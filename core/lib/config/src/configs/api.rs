@@ -231,12 +231,133 @@ pub struct Web3Config {
     /// Max difference between blocks in `eth_getLogs` method.
     pub max_block_range: u32,
     pub chain_id: u64,
+    /// Max number of blocks returned by `eth_feeHistory` method.
+    /// Bigger `blockCount` values are clamped to it rather than rejected.
+    #[serde(default = "Web3Config::default_max_fee_history_block_count")]
+    pub max_fee_history_block_count: u64,
+    /// Max number of `rewardPercentiles` accepted by `eth_feeHistory` method.
+    #[serde(default = "Web3Config::default_max_fee_history_reward_percentiles")]
+    pub max_fee_history_reward_percentiles: u64,
+    /// Max number of the latest blocks whose logs are kept in memory by the web3 server.
+    /// Zero disables the logs index.
+    #[serde(default = "Web3Config::default_logs_index_max_blocks")]
+    pub logs_index_max_blocks: u32,
+    /// Interval of polling the storage for the new and the reverted blocks to update the logs index.
+    #[serde(default = "Web3Config::default_logs_index_update_interval_sec")]
+    pub logs_index_update_interval_sec: u64,
+    /// If `true`, the `pending` block tag refers to the last block saved by the server,
+    /// including the blocks that are not confirmed on L1 yet. Otherwise it's an alias for `latest`.
+    #[serde(default = "Web3Config::default_pending_includes_unconfirmed_blocks")]
+    pub pending_includes_unconfirmed_blocks: bool,
+    /// If `true`, the `latest` block tag refers to the last committed block,
+    /// otherwise to the last finalized one.
+    #[serde(default)]
+    pub latest_includes_unfinalized_blocks: bool,
+    /// If `true`, blocks are returned without the `totalDifficulty` field,
+    /// otherwise it's always zero like the `difficulty` field.
+    #[serde(default)]
+    pub omit_total_difficulty: bool,
+    /// If `true`, the details of internal errors are sent to clients. Should be used for development only.
+    #[serde(default)]
+    pub reveal_internal_errors: bool,
+    /// Time after which a transaction that is still in the mempool is reported as possibly stuck.
+    #[serde(default = "Web3Config::default_stuck_tx_threshold_sec")]
+    pub stuck_tx_threshold_sec: u64,
 }
 
 impl Web3Config {
+    /// Upper bound for `logs_index_max_blocks`: the whole index is held in memory
+    /// and rebuilt from the database on every server start.
+    pub const LOGS_INDEX_MAX_BLOCKS_LIMIT: u32 = 10_000;
+
     pub fn bind_addr(&self) -> SocketAddr {
         SocketAddr::new("0.0.0.0".parse().unwrap(), self.port)
     }
+
+    // Defaults of the options added after the initial web3 server release,
+    // so environments without them keep working. Must match `etc/env/base/api.toml`.
+
+    fn default_max_fee_history_block_count() -> u64 {
+        1024
+    }
+
+    fn default_max_fee_history_reward_percentiles() -> u64 {
+        100
+    }
+
+    fn default_logs_index_max_blocks() -> u32 {
+        128
+    }
+
+    fn default_logs_index_update_interval_sec() -> u64 {
+        1
+    }
+
+    fn default_pending_includes_unconfirmed_blocks() -> bool {
+        true
+    }
+
+    fn default_stuck_tx_threshold_sec() -> u64 {
+        600
+    }
+
+    pub fn logs_index_update_interval(&self) -> Duration {
+        Duration::from_secs(self.logs_index_update_interval_sec)
+    }
+
+    pub fn stuck_tx_threshold(&self) -> Duration {
+        Duration::from_secs(self.stuck_tx_threshold_sec)
+    }
+
+    /// Checks that the configuration values are consistent with each other.
+    /// Returns the description of the first invalid value found.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.port == 0 {
+            return Err("API_WEB3_PORT must be greater than 0".to_string());
+        }
+        if self.url.is_empty() {
+            return Err("API_WEB3_URL must not be empty".to_string());
+        }
+        if self.max_block_range == 0 {
+            return Err("API_WEB3_MAX_BLOCK_RANGE must be greater than 0".to_string());
+        }
+        if self.chain_id == 0 {
+            return Err("API_WEB3_CHAIN_ID must be greater than 0".to_string());
+        }
+        if self.max_fee_history_block_count == 0 {
+            return Err("API_WEB3_MAX_FEE_HISTORY_BLOCK_COUNT must be greater than 0".to_string());
+        }
+        if self.max_fee_history_block_count > u32::MAX as u64 {
+            return Err(format!(
+                "API_WEB3_MAX_FEE_HISTORY_BLOCK_COUNT ({}) must not exceed the max block number ({})",
+                self.max_fee_history_block_count,
+                u32::MAX
+            ));
+        }
+        // Otherwise every `eth_feeHistory` call with reward percentiles is rejected.
+        if self.max_fee_history_reward_percentiles == 0 {
+            return Err(
+                "API_WEB3_MAX_FEE_HISTORY_REWARD_PERCENTILES must be greater than 0".to_string(),
+            );
+        }
+        if self.logs_index_max_blocks > Self::LOGS_INDEX_MAX_BLOCKS_LIMIT {
+            return Err(format!(
+                "API_WEB3_LOGS_INDEX_MAX_BLOCKS ({}) must not exceed {}",
+                self.logs_index_max_blocks,
+                Self::LOGS_INDEX_MAX_BLOCKS_LIMIT
+            ));
+        }
+        if self.logs_index_max_blocks > 0 && self.logs_index_update_interval_sec == 0 {
+            return Err(
+                "API_WEB3_LOGS_INDEX_UPDATE_INTERVAL_SEC must be greater than 0".to_string(),
+            );
+        }
+        // Otherwise every transaction in the mempool is reported as possibly stuck.
+        if self.stuck_tx_threshold_sec == 0 {
+            return Err("API_WEB3_STUCK_TX_THRESHOLD_SEC must be greater than 0".to_string());
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
@@ -284,6 +405,15 @@ mod tests {
                 url: "http://127.0.0.1:3002".into(),
                 max_block_range: 10,
                 chain_id: 240,
+                max_fee_history_block_count: 1024,
+                max_fee_history_reward_percentiles: 100,
+                logs_index_max_blocks: 128,
+                logs_index_update_interval_sec: 1,
+                pending_includes_unconfirmed_blocks: true,
+                latest_includes_unfinalized_blocks: false,
+                omit_total_difficulty: false,
+                reveal_internal_errors: false,
+                stuck_tx_threshold_sec: 600,
             },
             private: PrivateApiConfig {
                 port: 8090,
@@ -327,6 +457,15 @@ API_WEB3_PORT="3002"
 API_WEB3_URL="http://127.0.0.1:3002"
 API_WEB3_CHAIN_ID="240"
 API_WEB3_MAX_BLOCK_RANGE="10"
+API_WEB3_MAX_FEE_HISTORY_BLOCK_COUNT="1024"
+API_WEB3_MAX_FEE_HISTORY_REWARD_PERCENTILES="100"
+API_WEB3_LOGS_INDEX_MAX_BLOCKS="128"
+API_WEB3_LOGS_INDEX_UPDATE_INTERVAL_SEC="1"
+API_WEB3_PENDING_INCLUDES_UNCONFIRMED_BLOCKS="true"
+API_WEB3_LATEST_INCLUDES_UNFINALIZED_BLOCKS="false"
+API_WEB3_OMIT_TOTAL_DIFFICULTY="false"
+API_WEB3_REVEAL_INTERNAL_ERRORS="false"
+API_WEB3_STUCK_TX_THRESHOLD_SEC="600"
 API_PRIVATE_PORT="8090"
 API_PRIVATE_URL="http://127.0.0.1:8090"
 API_PROVER_PORT="8088"
@@ -371,4 +510,105 @@ API_PROMETHEUS_PORT="3312"
             SocketAddr::new(bind_broadcast_addr, config.web3.port)
         );
     }
+
+    /// Checks that invalid web3 configurations are rejected.
+    #[test]
+    fn web3_validate() {
+        let config = expected_config().web3;
+        assert_eq!(config.validate(), Ok(()));
+
+        let invalid_configs = vec![
+            (
+                Web3Config {
+                    port: 0,
+                    ..config.clone()
+                },
+                "API_WEB3_PORT",
+            ),
+            (
+                Web3Config {
+                    url: String::new(),
+                    ..config.clone()
+                },
+                "API_WEB3_URL",
+            ),
+            (
+                Web3Config {
+                    max_block_range: 0,
+                    ..config.clone()
+                },
+                "API_WEB3_MAX_BLOCK_RANGE",
+            ),
+            (
+                Web3Config {
+                    chain_id: 0,
+                    ..config.clone()
+                },
+                "API_WEB3_CHAIN_ID",
+            ),
+            (
+                Web3Config {
+                    max_fee_history_block_count: 0,
+                    ..config.clone()
+                },
+                "API_WEB3_MAX_FEE_HISTORY_BLOCK_COUNT",
+            ),
+            (
+                Web3Config {
+                    max_fee_history_block_count: u32::MAX as u64 + 1,
+                    ..config.clone()
+                },
+                "API_WEB3_MAX_FEE_HISTORY_BLOCK_COUNT",
+            ),
+            (
+                Web3Config {
+                    max_fee_history_reward_percentiles: 0,
+                    ..config.clone()
+                },
+                "API_WEB3_MAX_FEE_HISTORY_REWARD_PERCENTILES",
+            ),
+            (
+                Web3Config {
+                    logs_index_max_blocks: Web3Config::LOGS_INDEX_MAX_BLOCKS_LIMIT + 1,
+                    ..config.clone()
+                },
+                "API_WEB3_LOGS_INDEX_MAX_BLOCKS",
+            ),
+            (
+                Web3Config {
+                    logs_index_update_interval_sec: 0,
+                    ..config.clone()
+                },
+                "API_WEB3_LOGS_INDEX_UPDATE_INTERVAL_SEC",
+            ),
+            (
+                Web3Config {
+                    stuck_tx_threshold_sec: 0,
+                    ..config.clone()
+                },
+                "API_WEB3_STUCK_TX_THRESHOLD_SEC",
+            ),
+        ];
+        for (invalid_config, variable) in invalid_configs {
+            let err = invalid_config.validate().unwrap_err();
+            assert!(err.starts_with(variable), "{}", err);
+        }
+    }
+
+    /// Checks that the web3 options missing from the environment fall back to the defaults
+    /// from `etc/env/base/api.toml`.
+    #[test]
+    fn web3_defaults() {
+        let vars = vec![
+            ("API_WEB3_PORT", "3002"),
+            ("API_WEB3_URL", "http://127.0.0.1:3002"),
+            ("API_WEB3_CHAIN_ID", "240"),
+            ("API_WEB3_MAX_BLOCK_RANGE", "10"),
+        ];
+        let vars = vars
+            .into_iter()
+            .map(|(name, value)| (name.to_owned(), value.to_owned()));
+        let config: Web3Config = envy::prefixed("API_WEB3_").from_iter(vars).unwrap();
+        assert_eq!(config, expected_config().web3);
+    }
 }
@@ -0,0 +1 @@
+DROP INDEX IF EXISTS ix_balances_coin_id;
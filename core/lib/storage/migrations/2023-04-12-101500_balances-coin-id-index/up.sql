@@ -0,0 +1 @@
+CREATE INDEX IF NOT EXISTS ix_balances_coin_id ON balances (coin_id);
@@ -0,0 +1,2 @@
+DROP VIEW IF EXISTS block_proofs;
+DROP INDEX IF EXISTS aggregate_operations_proof_range_idx;
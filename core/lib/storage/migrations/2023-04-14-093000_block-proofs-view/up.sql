@@ -0,0 +1,14 @@
+-- Proof operations have no binding table, so they are bound to blocks by the range they include.
+CREATE INDEX IF NOT EXISTS aggregate_operations_proof_range_idx ON aggregate_operations (from_block, to_block)
+    WHERE action_type = 'PublishProofBlocksOnchain' AND confirmed = true;
+
+CREATE OR REPLACE VIEW block_proofs AS
+SELECT
+    blocks.number AS block_number,
+    eth_operations.final_hash
+FROM blocks
+    INNER JOIN aggregate_operations ON blocks.number BETWEEN aggregate_operations.from_block AND aggregate_operations.to_block
+    INNER JOIN eth_aggregated_ops_binding ON aggregate_operations.id = eth_aggregated_ops_binding.op_id
+    INNER JOIN eth_operations ON eth_operations.id = eth_aggregated_ops_binding.eth_op_id
+WHERE aggregate_operations.confirmed = true
+    AND aggregate_operations.action_type = 'PublishProofBlocksOnchain';
@@ -535,6 +535,60 @@ impl<'a, 'c> AccountSchema<'a, 'c> {
         Ok(result)
     }
 
+    /// Returns the nonce of the account as of the given block, zero if the account
+    /// doesn't exist or hasn't sent any transactions by that block.
+    ///
+    /// Nonces only grow and are changed only by balance and public key updates,
+    /// so the nonce is the biggest new nonce among these updates.
+    pub async fn get_account_nonce_for_block(
+        &mut self,
+        address: Address,
+        block_number: BlockNumber,
+    ) -> QueryResult<Nonce> {
+        let start = Instant::now();
+        let mut transaction = self.0.start_transaction().await?;
+
+        let account_id = transaction
+            .chain()
+            .account_schema()
+            .account_id_by_address(address)
+            .await?;
+        let account_id = match account_id {
+            Some(id) => id,
+            None => {
+                return Ok(Nonce(0));
+            }
+        };
+
+        let nonce = sqlx::query!(
+            r#"
+                SELECT GREATEST(
+                    (
+                        SELECT MAX(new_nonce) FROM account_balance_updates
+                        WHERE account_id = $1 AND block_number <= $2
+                    ),
+                    (
+                        SELECT MAX(new_nonce) FROM account_pubkey_updates
+                        WHERE account_id = $1 AND block_number <= $2
+                    )
+                ) AS "nonce?"
+            "#,
+            i64::from(account_id.0),
+            i64::from(block_number.0)
+        )
+        .fetch_one(transaction.conn())
+        .await?
+        .nonce;
+
+        transaction.commit().await?;
+        metrics::histogram!(
+            "sql.chain.account.get_account_nonce_for_block",
+            start.elapsed()
+        );
+
+        Ok(Nonce(nonce.unwrap_or_default() as u32))
+    }
+
     pub async fn get_account_nft_balance(&mut self, address: Address) -> QueryResult<u32> {
         let start = Instant::now();
         let mut transaction = self.0.start_transaction().await?;
@@ -575,6 +629,27 @@ impl<'a, 'c> AccountSchema<'a, 'c> {
         Ok(balance)
     }
 
+    /// Returns the sum of balances of the token over all the accounts.
+    ///
+    /// The sum is computed over the `balances` rows of the token found by the `coin_id` index,
+    /// callers exposed to the public should cache the result.
+    pub async fn get_token_total_supply(&mut self, token_id: TokenId) -> QueryResult<BigUint> {
+        let start = Instant::now();
+        let total_supply = sqlx::query!(
+            r#"
+                SELECT COALESCE(SUM(balance), 0) AS "total_supply!" FROM balances
+                WHERE coin_id = $1
+            "#,
+            token_id.0 as i32
+        )
+        .fetch_one(self.0.conn())
+        .await?
+        .total_supply;
+
+        metrics::histogram!("sql.chain.account.get_token_total_supply", start.elapsed());
+        Ok(total_supply.to_bigint().unwrap().to_biguint().unwrap())
+    }
+
     pub async fn get_nft_owner(&mut self, token_id: TokenId) -> QueryResult<Option<AccountId>> {
         let start = Instant::now();
 
@@ -362,6 +362,7 @@ impl<'a, 'c> BlockSchema<'a, 'c> {
         //   block number, ethereum transaction hash, action type and action creation timestamp;
         // - joins the `blocks` table with result of the join twice: once for committed operations
         //   and verified operations;
+        // - joins the `block_proofs` view, which binds the proof operations to the blocks they include;
         // - collects the {limit} blocks in the descending order with the data gathered above.
         let details = sqlx::query_as!(
             StorageBlockDetails,
@@ -393,11 +394,14 @@ impl<'a, 'c> BlockSchema<'a, 'c> {
                 blocks.root_hash AS "new_state_root!",
                 blocks.block_size AS "block_size!",
                 committed.final_hash AS "commit_tx_hash?",
+                proved.final_hash AS "prove_tx_hash?",
                 verified.final_hash AS "verify_tx_hash?",
+                verified.final_hash AS "execute_tx_hash?",
                 committed.created_at AS "committed_at!",
                 verified.created_at AS "verified_at?"
             FROM blocks
                      INNER JOIN aggr_comm committed ON blocks.number = committed.block_number
+                     LEFT JOIN block_proofs proved ON blocks.number = proved.block_number
                      LEFT JOIN aggr_exec verified ON blocks.number = verified.block_number
             WHERE
                 blocks.number <= $1
@@ -426,6 +430,7 @@ impl<'a, 'c> BlockSchema<'a, 'c> {
         //   block number, ethereum transaction hash, action type and action creation timestamp;
         // - joins the `blocks` table with result of the join twice: once for committed operations
         //   and verified operations;
+        // - joins the `block_proofs` view, which binds the proof operations to the blocks they include;
         // - collects the {limit} blocks in the ascending order with the data gathered above.
         let details = sqlx::query_as!(
             StorageBlockDetails,
@@ -457,11 +462,14 @@ impl<'a, 'c> BlockSchema<'a, 'c> {
                 blocks.root_hash AS "new_state_root!",
                 blocks.block_size AS "block_size!",
                 committed.final_hash AS "commit_tx_hash?",
+                proved.final_hash AS "prove_tx_hash?",
                 verified.final_hash AS "verify_tx_hash?",
+                verified.final_hash AS "execute_tx_hash?",
                 committed.created_at AS "committed_at!",
                 verified.created_at AS "verified_at?"
             FROM blocks
                      INNER JOIN aggr_comm committed ON blocks.number = committed.block_number
+                     LEFT JOIN block_proofs proved ON blocks.number = proved.block_number
                      LEFT JOIN aggr_exec verified ON blocks.number = verified.block_number
             WHERE
                 blocks.number >= $1
@@ -495,7 +503,7 @@ impl<'a, 'c> BlockSchema<'a, 'c> {
     }
 
     /// Performs a database search with an uncertain query, which can be either of:
-    /// - Hash of commit/verify Ethereum transaction for the block.
+    /// - Hash of commit/prove/verify Ethereum transaction for the block.
     /// - The state root hash of the block.
     /// - The number of the block.
     ///
@@ -530,8 +538,10 @@ impl<'a, 'c> BlockSchema<'a, 'c> {
         //   block number, ethereum transaction hash, action type and action creation timestamp;
         // - joins the `blocks` table with result of the join twice: once for committed operations
         //   and verified operations;
+        // - joins the `block_proofs` view, which binds the proof operations to the blocks they include;
         // - takes the only block that satisfies one of the following criteria
         //   + query equals to the ETH commit transaction hash (in form of `0x00{..}00`);
+        //   + query equals to the ETH prove transaction hash (in form of `0x00{..}00`);
         //   + query equals to the ETH verify transaction hash (in form of `0x00{..}00`);
         //   + query equals to the state hash obtained in the block (in form of `sync-bl:00{..}00`);
         //   + query equals to the number of the block.
@@ -565,14 +575,18 @@ impl<'a, 'c> BlockSchema<'a, 'c> {
                 blocks.root_hash AS "new_state_root!",
                 blocks.block_size AS "block_size!",
                 committed.final_hash AS "commit_tx_hash?",
+                proved.final_hash AS "prove_tx_hash?",
                 verified.final_hash AS "verify_tx_hash?",
+                verified.final_hash AS "execute_tx_hash?",
                 committed.created_at AS "committed_at!",
                 verified.created_at AS "verified_at?"
             FROM blocks
                      INNER JOIN aggr_comm committed ON blocks.number = committed.block_number
+                     LEFT JOIN block_proofs proved ON blocks.number = proved.block_number
                      LEFT JOIN aggr_exec verified ON blocks.number = verified.block_number
             WHERE false
                 OR committed.final_hash = $1
+                OR proved.final_hash = $1
                 OR verified.final_hash = $1
                 OR blocks.root_hash = $1
                 OR blocks.number = $2
@@ -67,9 +67,18 @@ pub struct StorageBlockDetails {
     #[serde(with = "OptionBytesToHexSerde::<ZeroxPrefix>")]
     pub commit_tx_hash: Option<Vec<u8>>,
 
+    /// Not serialized to keep the API v0.1 block details unchanged.
+    #[serde(skip)]
+    pub prove_tx_hash: Option<Vec<u8>>,
+
     #[serde(with = "OptionBytesToHexSerde::<ZeroxPrefix>")]
     pub verify_tx_hash: Option<Vec<u8>>,
 
+    /// Hash of the `ExecuteBlocks` transaction, the one that finalizes the block.
+    /// It's the same as `verify_tx_hash`, which keeps its name for API v0.1.
+    #[serde(skip)]
+    pub execute_tx_hash: Option<Vec<u8>>,
+
     pub committed_at: DateTime<Utc>,
 
     pub verified_at: Option<DateTime<Utc>>,
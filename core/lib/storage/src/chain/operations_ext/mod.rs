@@ -1718,7 +1718,8 @@ impl<'a, 'c> OperationsExtSchema<'a, 'c> {
                         nonce,
                         block_index,
                         from_account,
-                        to_account
+                        to_account,
+                        operation
                     FROM executed_transactions
                     WHERE tx_hash = $1
                 ), priority_op AS (
@@ -1728,7 +1729,8 @@ impl<'a, 'c> OperationsExtSchema<'a, 'c> {
                         priority_op_serialid as nonce,
                         block_index,
                         from_account,
-                        to_account
+                        to_account,
+                        NULL::jsonb as operation
                     FROM executed_priority_operations
                     WHERE tx_hash = $1 OR eth_hash = $1
                 ),
@@ -1744,6 +1746,7 @@ impl<'a, 'c> OperationsExtSchema<'a, 'c> {
                     block_index as "block_index?",
                     from_account as "from_account!",
                     to_account as "to_account?",
+                    operation as "operation?",
                     root_hash as "block_hash!"
                 FROM everything
                 LEFT JOIN blocks
@@ -1758,6 +1761,8 @@ impl<'a, 'c> OperationsExtSchema<'a, 'c> {
         Ok(result)
     }
 
+    /// Returns `None` unless the transaction is in a committed block, so the transactions
+    /// of reverted blocks have no receipt even if they are still stored as executed.
     pub async fn web3_receipt_by_hash(
         &mut self,
         hash: &[u8],
@@ -1824,6 +1829,8 @@ impl<'a, 'c> OperationsExtSchema<'a, 'c> {
         Ok(tx)
     }
 
+    /// Returns the receipts of the transactions in the committed blocks of the given range,
+    /// so the transactions of reverted blocks are skipped even if they are still stored as executed.
     pub async fn web3_receipts(
         &mut self,
         from_block: BlockNumber,
@@ -185,6 +185,8 @@ pub struct Web3TxData {
     pub block_index: Option<i32>,
     pub from_account: Vec<u8>,
     pub to_account: Option<Vec<u8>>,
+    /// Executed operation, `None` for priority operations and `null` for failed transactions.
+    pub operation: Option<Value>,
 }
 
 #[derive(Debug, FromRow, Clone, PartialEq)]
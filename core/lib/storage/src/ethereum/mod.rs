@@ -698,6 +698,46 @@ impl<'a, 'c> EthereumSchema<'a, 'c> {
         Ok(final_hash)
     }
 
+    /// Loads the aggregated operation sent within the Ethereum transaction with the given hash,
+    /// along with the hash of the transaction that was eventually mined.
+    ///
+    /// All the hashes of resent Ethereum transactions resolve to the same operation.
+    pub async fn aggregated_op_by_tx_hash(
+        &mut self,
+        hash: &H256,
+    ) -> QueryResult<Option<(StoredAggregatedOperation, Option<H256>)>> {
+        let start = Instant::now();
+        let record = sqlx::query!(
+            r#"
+            SELECT aggregate_operations.*, eth_operations.final_hash FROM eth_tx_hashes
+                INNER JOIN eth_operations ON eth_operations.id = eth_tx_hashes.eth_op_id
+                INNER JOIN eth_aggregated_ops_binding ON eth_aggregated_ops_binding.eth_op_id = eth_operations.id
+                INNER JOIN aggregate_operations ON aggregate_operations.id = eth_aggregated_ops_binding.op_id
+            WHERE eth_tx_hashes.tx_hash = $1
+            LIMIT 1
+            "#,
+            hash.as_bytes()
+        )
+        .fetch_optional(self.0.conn())
+        .await?;
+
+        let result = record.map(|record| {
+            let op = StoredAggregatedOperation {
+                id: record.id,
+                action_type: record.action_type,
+                arguments: record.arguments,
+                from_block: record.from_block,
+                to_block: record.to_block,
+                created_at: record.created_at,
+                confirmed: record.confirmed,
+            };
+            (op, record.final_hash.map(|hash| H256::from_slice(&hash)))
+        });
+
+        metrics::histogram!("sql.ethereum.aggregated_op_by_tx_hash", start.elapsed());
+        Ok(result)
+    }
+
     // Updates eth_parameters with given nonce and last block.
     // It updates last_verified_block only if it is greater than given last block.
     pub async fn update_eth_parameters(&mut self, last_block: BlockNumber) -> QueryResult<()> {
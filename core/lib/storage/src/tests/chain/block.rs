@@ -204,6 +204,10 @@ async fn test_find_block_by_height_or_hash(mut storage: StorageProcessor<'_>) ->
                 actual_block_detail.verify_tx_hash,
                 expected_block_detail.verify_tx_hash
             );
+            assert_eq!(
+                actual_block_detail.execute_tx_hash,
+                expected_block_detail.execute_tx_hash
+            );
         }
 
         Ok(())
@@ -233,7 +237,9 @@ async fn test_find_block_by_height_or_hash(mut storage: StorageProcessor<'_>) ->
             new_state_root: Default::default(),
             block_size: 0,
             commit_tx_hash: None,
+            prove_tx_hash: None,
             verify_tx_hash: None,
+            execute_tx_hash: None,
             committed_at: chrono::DateTime::from_utc(
                 chrono::NaiveDateTime::from_timestamp(0, 0),
                 chrono::Utc,
@@ -328,6 +334,7 @@ async fn test_find_block_by_height_or_hash(mut storage: StorageProcessor<'_>) ->
                     .confirm_eth_tx(&eth_tx_hash)
                     .await?;
                 current_block_detail.verify_tx_hash = Some(eth_tx_hash.as_ref().to_vec());
+                current_block_detail.execute_tx_hash = Some(eth_tx_hash.as_ref().to_vec());
             }
         }
 
@@ -1109,6 +1109,51 @@ async fn web3_receipts(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
     Ok(())
 }
 
+/// Checks that transactions of reverted blocks don't have receipts.
+#[db_test]
+async fn web3_receipts_of_reverted_block(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
+    let mut setup = TransactionsHistoryTestSetup::new();
+    setup.add_block(1);
+    setup.add_block(2);
+    commit_schema_data(&mut storage, &setup).await?;
+    commit_block(&mut storage, BlockNumber(1)).await?;
+    commit_block(&mut storage, BlockNumber(2)).await?;
+
+    let tx_hash = setup.get_tx_hash(1, 0).as_ref().to_vec();
+    let receipt = storage
+        .chain()
+        .operations_ext_schema()
+        .web3_receipt_by_hash(&tx_hash)
+        .await?;
+    assert_eq!(receipt.unwrap().tx_hash, tx_hash);
+
+    // Transactions of the reverted block are still stored as executed ones,
+    // until they are returned to the mempool.
+    storage
+        .chain()
+        .block_schema()
+        .remove_blocks(BlockNumber(1))
+        .await?;
+
+    let receipt = storage
+        .chain()
+        .operations_ext_schema()
+        .web3_receipt_by_hash(&tx_hash)
+        .await?;
+    assert!(receipt.is_none());
+    let receipts = storage
+        .chain()
+        .operations_ext_schema()
+        .web3_receipts(BlockNumber(1), BlockNumber(2))
+        .await?;
+    // Only the receipts of the remaining block 1 are returned.
+    assert_eq!(receipts.len(), 10);
+    assert!(receipts.iter().all(|receipt| receipt.block_number == 1));
+    assert!(receipts.iter().all(|receipt| receipt.tx_hash != tx_hash));
+
+    Ok(())
+}
+
 /// Test getting swap for account using different storage methods
 #[db_test]
 async fn test_getting_swap_for_acc(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
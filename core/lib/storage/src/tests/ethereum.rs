@@ -358,6 +358,74 @@ async fn ethereum_unprocessed(mut storage: StorageProcessor<'_>) -> QueryResult<
     Ok(())
 }
 
+/// Checks that aggregated operations can be found by the hashes of Ethereum transactions,
+/// including the hashes of resent transactions.
+#[db_test]
+async fn ethereum_aggregated_op_by_tx_hash(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
+    EthereumSchema(&mut storage).initialize_eth_data().await?;
+
+    let block_number = BlockNumber(1);
+    OperationsSchema(&mut storage)
+        .store_aggregated_action(gen_unique_aggregated_operation(
+            block_number,
+            AggregatedActionType::CommitBlocks,
+            BLOCK_SIZE_CHUNKS,
+        ))
+        .await?;
+    let op = OperationsSchema(&mut storage)
+        .get_aggregated_op_that_affects_block(AggregatedActionType::CommitBlocks, block_number)
+        .await?;
+    let op_id = op.as_ref().unwrap().0;
+
+    let params = EthereumTxParams::new("CommitBlocks".into(), op);
+    let response = EthereumSchema(&mut storage)
+        .save_new_eth_tx(
+            AggregatedActionType::CommitBlocks,
+            params.op.clone(),
+            params.deadline_block as i64,
+            params.gas_price.clone(),
+            params.raw_tx.clone(),
+        )
+        .await?;
+    EthereumSchema(&mut storage)
+        .add_hash_entry(response.id, &params.hash)
+        .await?;
+    // The transaction was resent with a higher gas price.
+    let resent_hash = H256::repeat_byte(0xaa);
+    EthereumSchema(&mut storage)
+        .add_hash_entry(response.id, &resent_hash)
+        .await?;
+
+    let unknown = EthereumSchema(&mut storage)
+        .aggregated_op_by_tx_hash(&H256::repeat_byte(0xbb))
+        .await?;
+    assert!(unknown.is_none());
+
+    for hash in &[params.hash, resent_hash] {
+        let (stored_op, final_hash) = EthereumSchema(&mut storage)
+            .aggregated_op_by_tx_hash(hash)
+            .await?
+            .unwrap();
+        assert_eq!(stored_op.id, op_id);
+        assert_eq!(stored_op.from_block, 1);
+        assert_eq!(stored_op.to_block, 1);
+        assert_eq!(final_hash, None);
+    }
+
+    EthereumSchema(&mut storage)
+        .confirm_eth_tx(&resent_hash)
+        .await?;
+    let (stored_op, final_hash) = EthereumSchema(&mut storage)
+        .aggregated_op_by_tx_hash(&params.hash)
+        .await?
+        .unwrap();
+    assert_eq!(stored_op.id, op_id);
+    assert!(stored_op.confirmed);
+    assert_eq!(final_hash, Some(resent_hash));
+
+    Ok(())
+}
+
 /// Simple test for store/load of (average) gas price.
 #[db_test]
 async fn ethereum_gas_update(mut storage: StorageProcessor<'_>) -> QueryResult<()> {
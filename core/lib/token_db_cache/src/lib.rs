@@ -11,6 +11,7 @@ use zksync_types::{Token, TokenId, TokenLike, NFT};
 pub struct TokenDBCache {
     cache: Arc<RwLock<HashMap<TokenLike, (Token, Instant)>>>,
     nft_tokens: Arc<RwLock<HashMap<TokenId, NFT>>>,
+    all_tokens: Arc<RwLock<Option<(Vec<Token>, Instant)>>>,
     token_invalidate_cache: Duration,
 }
 
@@ -123,6 +124,24 @@ impl TokenDBCache {
         }
     }
 
+    /// Returns all the tokens sorted by id. The list is cached and reloaded from the database
+    /// once the cache invalidation period has passed, like the individual tokens.
+    pub async fn get_cached_tokens(
+        &self,
+        storage: &mut StorageProcessor<'_>,
+    ) -> anyhow::Result<Vec<Token>> {
+        if let Some((tokens, update_time)) = self.all_tokens.read().await.as_ref() {
+            if update_time.elapsed() < self.token_invalidate_cache {
+                return Ok(tokens.clone());
+            }
+        }
+        let mut tokens = Self::get_all_tokens(storage).await?;
+        tokens.sort_by_key(|token| token.id);
+        *self.all_tokens.write().await = Some((tokens.clone(), Instant::now()));
+
+        Ok(tokens)
+    }
+
     pub async fn get_all_tokens(
         storage: &mut StorageProcessor<'_>,
     ) -> Result<Vec<Token>, anyhow::Error> {
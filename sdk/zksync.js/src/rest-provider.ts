@@ -1,4 +1,4 @@
-import Axios from 'axios';
+import Axios, { AxiosResponse } from 'axios';
 import { BigNumber } from 'ethers';
 import { SyncProvider } from './provider-interface';
 import * as types from './types';
@@ -59,6 +59,22 @@ export class RESTError extends Error {
     }
 }
 
+// Codes of the errors returned for missing resources, the corresponding methods return `null` for them.
+const NOT_FOUND_ERROR_CODES = [204, 205, 209, 210, 211];
+
+// API errors are returned in the `Response` envelope with 4xx/5xx statuses. Other responses with
+// these statuses (e.g. an error page of a proxy) are not API responses, so they are thrown.
+function apiResponse<T>(resp: AxiosResponse): Response<T> {
+    const isSuccess = resp.status >= 200 && resp.status < 300;
+    const data = resp.data;
+    const isErrorEnvelope =
+        data != null && typeof data === 'object' && 'request' in data && 'status' in data && 'error' in data;
+    if (!isSuccess && !isErrorEnvelope) {
+        throw new Error(`zkSync API request failed with status ${resp.status}`);
+    }
+    return data;
+}
+
 export class RestProvider extends SyncProvider {
     public static readonly MAX_LIMIT = 100;
 
@@ -94,16 +110,19 @@ export class RestProvider extends SyncProvider {
         }
     }
 
+    parseOptionalResponse<T>(response: Response<T>): T | null {
+        if (response.status === 'error' && NOT_FOUND_ERROR_CODES.indexOf(response.error.code) !== -1) {
+            return null;
+        }
+        return this.parseResponse(response);
+    }
+
     async get<T>(url: string): Promise<Response<T>> {
-        return await Axios.get(url).then((resp) => {
-            return resp.data;
-        });
+        return await Axios.get(url, { validateStatus: () => true }).then((resp) => apiResponse<T>(resp));
     }
 
     async post<T>(url: string, body: any): Promise<Response<T>> {
-        return await Axios.post(url, body).then((resp) => {
-            return resp.data;
-        });
+        return await Axios.post(url, body, { validateStatus: () => true }).then((resp) => apiResponse<T>(resp));
     }
 
     async accountInfoDetailed(
@@ -117,7 +136,7 @@ export class RestProvider extends SyncProvider {
         idOrAddress: number | types.Address,
         infoType: 'committed' | 'finalized'
     ): Promise<types.ApiAccountInfo> {
-        return this.parseResponse(await this.accountInfoDetailed(idOrAddress, infoType));
+        return this.parseOptionalResponse(await this.accountInfoDetailed(idOrAddress, infoType));
     }
 
     async toggle2FADetailed(data: types.Toggle2FARequest): Promise<Response<types.Toggle2FAResponse>> {
@@ -199,7 +218,7 @@ export class RestProvider extends SyncProvider {
     }
 
     async blockByPosition(blockPosition: types.BlockPosition): Promise<types.ApiBlockInfo> {
-        return this.parseResponse(await this.blockByPositionDetailed(blockPosition));
+        return this.parseOptionalResponse(await this.blockByPositionDetailed(blockPosition));
     }
 
     async blockTransactionsDetailed(
@@ -379,7 +398,7 @@ export class RestProvider extends SyncProvider {
     }
 
     async txStatus(txHash: string): Promise<types.ApiTxReceipt> {
-        return this.parseResponse(await this.txStatusDetailed(txHash));
+        return this.parseOptionalResponse(await this.txStatusDetailed(txHash));
     }
 
     async txDataDetailed(txHash: string): Promise<Response<types.ApiSignedTx>> {
@@ -387,7 +406,7 @@ export class RestProvider extends SyncProvider {
     }
 
     async txData(txHash: string): Promise<types.ApiSignedTx> {
-        return this.parseResponse(await this.txDataDetailed(txHash));
+        return this.parseOptionalResponse(await this.txDataDetailed(txHash));
     }
 
     async submitTxsBatchNewDetailed(
@@ -419,7 +438,7 @@ export class RestProvider extends SyncProvider {
     }
 
     async getBatch(batchHash: string): Promise<types.ApiBatchData> {
-        return this.parseResponse(await this.getBatchDetailed(batchHash));
+        return this.parseOptionalResponse(await this.getBatchDetailed(batchHash));
     }
 
     async getNFTDetailed(id: number): Promise<Response<types.NFTInfo>> {
@@ -427,7 +446,7 @@ export class RestProvider extends SyncProvider {
     }
 
     async getNFT(id: number): Promise<types.NFTInfo> {
-        const nft = this.parseResponse(await this.getNFTDetailed(id));
+        const nft = this.parseOptionalResponse(await this.getNFTDetailed(id));
 
         // If the NFT does not exist, throw an exception
         if (nft == null) {
@@ -441,7 +460,7 @@ export class RestProvider extends SyncProvider {
     }
 
     async getNFTOwner(id: number): Promise<number> {
-        return this.parseResponse(await this.getNFTOwnerDetailed(id));
+        return this.parseOptionalResponse(await this.getNFTOwnerDetailed(id));
     }
 
     async getNFTIdByTxHashDetailed(txHash: string): Promise<Response<number>> {
@@ -449,7 +468,7 @@ export class RestProvider extends SyncProvider {
     }
 
     async getNFTIdByTxHash(txHash: string): Promise<number> {
-        return this.parseResponse(await this.getNFTIdByTxHashDetailed(txHash));
+        return this.parseOptionalResponse(await this.getNFTIdByTxHashDetailed(txHash));
     }
 
     async notifyAnyTransaction(hash: string, action: 'COMMIT' | 'VERIFY'): Promise<types.ApiTxReceipt> {
@@ -422,7 +422,9 @@ export interface ApiBlockInfo {
     newStateRoot: string;
     blockSize: number;
     commitTxHash?: string;
+    proveTxHash?: string;
     verifyTxHash?: string;
+    executeTxHash?: string;
     committedAt: string;
     finalizedAt?: string;
     status: 'committed' | 'finalized';